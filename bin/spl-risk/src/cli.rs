@@ -1,8 +1,11 @@
 use clap::Parser;
 use solana_sdk::pubkey::Pubkey;
+use std::path::PathBuf;
 use std::str::FromStr;
 use std::time::Duration;
 
+pub const DEFAULT_RPC_URL: &str = "https://api.mainnet-beta.solana.com";
+
 #[derive(Parser, Debug)]
 #[command(
     name = "spl-risk",
@@ -17,24 +20,20 @@ pub struct Cli {
     #[arg(value_parser = parse_pubkey)]
     pub mint_address: Pubkey,
 
-    /// Solana RPC endpoint URL
-    #[arg(
-        short = 'r',
-        long,
-        env = "SOLANA_RPC_URL",
-        default_value = "https://api.mainnet-beta.solana.com"
-    )]
-    pub rpc_url: String,
-
-    /// Risk profile: conservative, balanced, or degenerate
-    #[arg(
-        short = 'p',
-        long,
-        default_value = "balanced",
-        value_parser = ["conservative", "balanced", "degenerate"]
-    )]
+    /// Solana RPC endpoint URL; repeat or comma-separate to use several with failover
+    /// [default: rpc.endpoints from the profile, or https://api.mainnet-beta.solana.com]
+    #[arg(short = 'r', long, env = "SOLANA_RPC_URL", value_delimiter = ',')]
+    pub rpc_url: Vec<String>,
+
+    /// Risk profile: conservative, balanced, degenerate, or the name of a
+    /// profile file in ~/.config/spl-risk/profiles/
+    #[arg(short = 'p', long, default_value = "balanced")]
     pub profile: String,
 
+    /// Load a custom risk profile from a TOML or YAML file
+    #[arg(short = 'c', long, conflicts_with = "profile")]
+    pub config: Option<PathBuf>,
+
     /// Output results as JSON
     #[arg(short = 'j', long)]
     pub json: bool,
@@ -47,6 +46,60 @@ pub struct Cli {
     #[arg(short = 't', long, default_value = "10", value_parser = parse_duration)]
     pub timeout: Duration,
 
+    /// Total time budget for the analysis in seconds (0 = no limit)
+    #[arg(long, default_value = "120", value_parser = parse_duration)]
+    pub deadline: Duration,
+
+    /// Retries for rate-limited, failing or timed-out RPC requests
+    #[arg(long, default_value = "3")]
+    pub retries: u32,
+
+    /// Cross-check the mint and metadata accounts across N endpoints
+    /// [default: rpc.quorum from the profile, or off]
+    #[arg(long, value_name = "N")]
+    pub quorum: Option<usize>,
+
+    /// Count every holder with getProgramAccounts instead of the 20 largest accounts
+    /// (exact, but heavy for widely held tokens)
+    #[arg(long)]
+    pub deep_holders: bool,
+
+    /// Decode the mint's transactions into an authority timeline (up to 200 transactions)
+    #[arg(long)]
+    pub authority_history: bool,
+
+    /// Read the transactions of the mint's first 20 slots for snipers and buys
+    /// bundled with the creator
+    #[arg(long)]
+    pub launch_analysis: bool,
+
+    /// Simulate a transfer from a top holder to a new wallet to catch tokens that
+    /// can't be sold (simulateTransaction, nothing is sent)
+    #[arg(long)]
+    pub simulate: bool,
+
+    /// Extra labeled addresses (TOML or YAML), added to the built-in pools, burn
+    /// addresses and exchanges
+    #[arg(long, value_name = "FILE")]
+    pub labels: Option<PathBuf>,
+
+    /// Extra well-known tokens (TOML or YAML) checked for impersonation, added to
+    /// the built-in USDC, USDT, JUP, BONK, ...
+    #[arg(long, value_name = "FILE")]
+    pub known_tokens: Option<PathBuf>,
+
+    /// Holder wallet ages looked up in parallel
+    #[arg(long, default_value = "4", value_name = "N")]
+    pub concurrency: usize,
+
+    /// Pages of 1000 signatures walked back to find a mint's or wallet's first transaction
+    #[arg(long, default_value = "10", value_name = "N")]
+    pub history_pages: usize,
+
+    /// Time budget in seconds for each signature history walk (0 = no limit)
+    #[arg(long, default_value = "15", value_parser = parse_duration, value_name = "SECS")]
+    pub history_time: Duration,
+
     /// Disable caching
     #[arg(long)]
     pub no_cache: bool,
@@ -3,20 +3,60 @@ use clap::Parser;
 
 mod cli;
 
-use cli::Cli;
+use cli::{Cli, DEFAULT_RPC_URL};
 use spl_risk_core::config::Config;
+use spl_risk_core::known_tokens::KnownTokens;
+use spl_risk_core::labels::LabelRegistry;
 use spl_risk_core::scoring::RiskAnalyzer;
-use spl_risk_rpc::SolanaRpcClient;
+use spl_risk_rpc::{RpcPolicy, SolanaRpcClient};
 
 #[tokio::main]
 async fn main() -> Result<()> {
     let cli = Cli::parse();
 
     // Load configuration
-    let config = Config::from_profile(&cli.profile)?;
+    let config = match &cli.config {
+        Some(path) => Config::from_file(path)?,
+        None => Config::from_profile(&cli.profile)?,
+    };
 
     // Initialize RPC client
-    let rpc_client = SolanaRpcClient::new(&cli.rpc_url, cli.timeout)?;
+    let policy = RpcPolicy {
+        request_timeout: cli.timeout,
+        deadline: (!cli.deadline.is_zero()).then_some(cli.deadline),
+        max_retries: cli.retries,
+        ..RpcPolicy::default()
+    };
+    let endpoints = if !cli.rpc_url.is_empty() {
+        cli.rpc_url.clone()
+    } else if !config.rpc.endpoints.is_empty() {
+        config.rpc.endpoints.clone()
+    } else {
+        vec![DEFAULT_RPC_URL.to_string()]
+    };
+    let quorum = cli.quorum.unwrap_or(config.rpc.quorum);
+    let mut labels = LabelRegistry::builtin();
+    if let Some(path) = &cli.labels {
+        labels.extend(LabelRegistry::from_file(path)?);
+    }
+    let mut known_tokens = KnownTokens::builtin();
+    if let Some(path) = &cli.known_tokens {
+        known_tokens.extend(KnownTokens::from_file(path)?);
+    }
+
+    let rpc_client = SolanaRpcClient::with_endpoints(&endpoints, policy)?
+        .with_quorum(quorum)?
+        .with_concurrency(cli.concurrency)
+        .with_deep_holders(cli.deep_holders)
+        .with_authority_history(cli.authority_history)
+        .with_launch_analysis(cli.launch_analysis)
+        .with_transfer_simulation(cli.simulate)
+        .with_labels(labels)
+        .with_known_tokens(known_tokens)
+        .with_history_budget(
+            cli.history_pages,
+            (!cli.history_time.is_zero()).then_some(cli.history_time),
+        );
 
     // Clear cache if requested
     if cli.no_cache {
@@ -47,7 +87,10 @@ async fn main() -> Result<()> {
     }
 
     // Create analyzer
+    #[cfg(not(feature = "lp-analysis"))]
     let rules = spl_risk_community::community_rules();
+    #[cfg(feature = "lp-analysis")]
+    let rules = spl_risk_community::heuristics::get_pro_rules();
     let analyzer = RiskAnalyzer::new(config, rpc_client, rules);
 
     // Analyze token
@@ -1,18 +1,51 @@
 use spl_risk_core::config::Config;
 use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::token::AuthorityKind;
 use spl_risk_core::model::{RiskReport, TokenData};
 
+/// Weight, status and holder description for an active authority, by what holds it.
+/// Only multisigs and known governance programs are discounted; other PDAs and
+/// programs, and unclassified authorities, are scored as a single wallet.
+fn classify_authority(
+    kind: Option<&AuthorityKind>,
+    wallet_weight: i32,
+    multisig_weight: i32,
+    program_weight: i32,
+) -> (i32, &'static str, String) {
+    match kind {
+        Some(kind @ AuthorityKind::Multisig { .. }) => {
+            (multisig_weight, "multisig", kind.describe())
+        }
+        Some(kind @ AuthorityKind::Pda { .. }) if kind.is_governed() => {
+            (program_weight, "program", kind.describe())
+        }
+        Some(kind @ (AuthorityKind::Pda { .. } | AuthorityKind::Program)) => {
+            (wallet_weight, "active", kind.describe())
+        }
+        Some(AuthorityKind::Wallet) | None => (wallet_weight, "active", "the owner".to_string()),
+    }
+}
+
 pub struct MintAuthorityRule;
 
 impl RiskRule for MintAuthorityRule {
     fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
         if token.mint_authority.is_some() {
             report.flags.mint_authority = true;
+            let (weight, status, holder) = classify_authority(
+                token.mint_authority_kind.as_ref(),
+                config.weights.mint_authority_active,
+                config.weights.mint_authority_multisig,
+                config.weights.mint_authority_program,
+            );
             report.add_rule(
                 "mint_authority_active",
-                config.weights.mint_authority_active,
-                "Mint authority is active - owner can create unlimited tokens",
-                Some("active"),
+                weight,
+                &format!(
+                    "Mint authority is active - {} can create unlimited tokens",
+                    holder
+                ),
+                Some(status),
             );
         } else {
             report.add_rule(
@@ -35,11 +68,20 @@ impl RiskRule for FreezeAuthorityRule {
     fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
         if token.freeze_authority.is_some() {
             report.flags.freeze_authority = true;
+            let (weight, status, holder) = classify_authority(
+                token.freeze_authority_kind.as_ref(),
+                config.weights.freeze_authority_active,
+                config.weights.freeze_authority_multisig,
+                config.weights.freeze_authority_program,
+            );
             report.add_rule(
                 "freeze_authority_active",
-                config.weights.freeze_authority_active,
-                "Freeze authority is active - owner can freeze token accounts",
-                Some("active"),
+                weight,
+                &format!(
+                    "Freeze authority is active - {} can freeze token accounts",
+                    holder
+                ),
+                Some(status),
             );
         } else {
             report.add_rule(
@@ -0,0 +1,254 @@
+use spl_risk_core::config::Config;
+use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::token::MintExtension;
+use spl_risk_core::model::{RiskReport, TokenData};
+
+pub struct PermanentDelegateRule;
+
+impl RiskRule for PermanentDelegateRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let delegate = token.extensions.iter().find_map(|ext| match ext {
+            MintExtension::PermanentDelegate { delegate } => *delegate,
+            _ => None,
+        });
+
+        if let Some(delegate) = delegate {
+            report.flags.permanent_delegate = true;
+            report.add_rule(
+                "permanent_delegate",
+                config.weights.permanent_delegate,
+                &format!(
+                    "Permanent delegate {} can transfer or burn tokens from any holder",
+                    delegate
+                ),
+                Some("active"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "permanent_delegate"
+    }
+}
+
+pub struct TransferHookRule;
+
+impl RiskRule for TransferHookRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        for ext in &token.extensions {
+            let MintExtension::TransferHook {
+                authority,
+                program_id,
+            } = ext
+            else {
+                continue;
+            };
+
+            match (program_id, authority) {
+                (Some(program_id), _) => {
+                    report.flags.transfer_hook = true;
+                    report.add_rule(
+                        "transfer_hook",
+                        config.weights.transfer_hook,
+                        &format!(
+                            "Transfer hook program {} runs on every transfer and can block sells",
+                            program_id
+                        ),
+                        Some("active"),
+                    );
+                }
+                // No hook yet, but the authority can install one at any time
+                (None, Some(authority)) => {
+                    report.add_rule(
+                        "transfer_hook",
+                        config.weights.transfer_hook / 2,
+                        &format!(
+                            "Transfer hook is unset, but {} can install one at any time",
+                            authority
+                        ),
+                        Some("dormant"),
+                    );
+                }
+                (None, None) => {}
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "transfer_hook"
+    }
+}
+
+pub struct TransferFeeRule;
+
+impl RiskRule for TransferFeeRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        for ext in &token.extensions {
+            let MintExtension::TransferFeeConfig {
+                fee_bps,
+                max_fee,
+                config_authority,
+                withdraw_authority,
+            } = ext
+            else {
+                continue;
+            };
+
+            if *fee_bps == 0 && config_authority.is_none() {
+                continue;
+            }
+
+            let mut description = format!(
+                "Transfer fee of {:.2}% (max {} base units)",
+                *fee_bps as f64 / 100.0,
+                max_fee
+            );
+            if let Some(authority) = config_authority {
+                description.push_str(&format!(", {} can change it", authority));
+            }
+            if let Some(authority) = withdraw_authority {
+                description.push_str(&format!(", withheld fees go to {}", authority));
+            }
+
+            report.add_rule(
+                "transfer_fee",
+                config.weights.transfer_fee,
+                &description,
+                Some(if *fee_bps > 0 { "active" } else { "adjustable" }),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "transfer_fee"
+    }
+}
+
+pub struct DefaultAccountFrozenRule;
+
+impl RiskRule for DefaultAccountFrozenRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let frozen = token
+            .extensions
+            .iter()
+            .any(|ext| matches!(ext, MintExtension::DefaultAccountState { frozen: true }));
+
+        if frozen {
+            report.add_rule(
+                "default_account_frozen",
+                config.weights.default_account_frozen,
+                "New token accounts start frozen - holders depend on the freeze authority to trade",
+                Some("frozen"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "default_account_state"
+    }
+}
+
+pub struct NonTransferableRule;
+
+impl RiskRule for NonTransferableRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        if token
+            .extensions
+            .iter()
+            .any(|ext| matches!(ext, MintExtension::NonTransferable))
+        {
+            report.add_rule(
+                "non_transferable",
+                config.weights.non_transferable,
+                "Token is non-transferable - it cannot be sold or moved",
+                Some("locked"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "non_transferable"
+    }
+}
+
+pub struct MintCloseAuthorityRule;
+
+impl RiskRule for MintCloseAuthorityRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let authority = token.extensions.iter().find_map(|ext| match ext {
+            MintExtension::MintCloseAuthority { authority } => *authority,
+            _ => None,
+        });
+
+        if let Some(authority) = authority {
+            report.add_rule(
+                "mint_close_authority",
+                config.weights.mint_close_authority,
+                &format!(
+                    "Mint close authority {} can close the mint once supply is zero",
+                    authority
+                ),
+                Some("active"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "mint_close_authority"
+    }
+}
+
+pub struct PausableRule;
+
+impl RiskRule for PausableRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        for ext in &token.extensions {
+            let MintExtension::Pausable { authority, paused } = ext else {
+                continue;
+            };
+
+            if *paused {
+                report.add_rule(
+                    "pausable",
+                    config.weights.pausable,
+                    "Token is currently PAUSED - transfers, mints and burns are halted",
+                    Some("paused"),
+                );
+            } else if let Some(authority) = authority {
+                report.add_rule(
+                    "pausable",
+                    config.weights.pausable,
+                    &format!("{} can pause all transfers at any time", authority),
+                    Some("active"),
+                );
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "pausable"
+    }
+}
+
+pub struct ConfidentialTransferRule;
+
+impl RiskRule for ConfidentialTransferRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        if token
+            .extensions
+            .iter()
+            .any(|ext| matches!(ext, MintExtension::ConfidentialTransfer { .. }))
+        {
+            report.add_rule(
+                "confidential_transfer",
+                config.weights.confidential_transfer,
+                "Confidential transfers enabled - balances can be hidden, distribution can't be fully verified",
+                Some("enabled"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "confidential_transfer"
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use spl_risk_core::config::Config;
+use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::report::LaunchMetrics;
+use spl_risk_core::model::token::{AuthorityAction, AuthorityEvent, AuthorityHistory};
+use spl_risk_core::model::{RiskReport, TokenData};
+
+/// Time after the mint's creation treated as its launch
+const LAUNCH_WINDOW_SECS: i64 = 72 * 3600;
+
+fn partial_note(history: &AuthorityHistory) -> &'static str {
+    if history.complete {
+        ""
+    } else {
+        " (partial history)"
+    }
+}
+
+/// Seconds between the mint's creation and `event`, when both are known
+fn since_creation(token: &TokenData, event: &AuthorityEvent) -> Option<i64> {
+    Some(event.timestamp? - token.creation_timestamp?)
+}
+
+pub struct AuthorityToFreshWalletRule;
+
+impl RiskRule for AuthorityToFreshWalletRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(history) = &token.authority_history else {
+            return;
+        };
+
+        let young_secs = config.thresholds.wallet_young_days as i64 * 86400;
+        let fresh = history.events.iter().find(|event| {
+            let AuthorityAction::SetAuthority {
+                authority_type,
+                new_authority: Some(_),
+                new_authority_first_seen: Some(first_seen),
+            } = &event.action
+            else {
+                return false;
+            };
+            if authority_type != "MintTokens" && authority_type != "FreezeAccount" {
+                return false;
+            }
+
+            let in_launch_window = since_creation(token, event)
+                .is_some_and(|secs| (0..=LAUNCH_WINDOW_SECS).contains(&secs));
+            let wallet_young = event
+                .timestamp
+                .is_some_and(|timestamp| timestamp - first_seen < young_secs);
+            in_launch_window && wallet_young
+        });
+
+        if let Some(AuthorityEvent {
+            action:
+                AuthorityAction::SetAuthority {
+                    authority_type,
+                    new_authority: Some(wallet),
+                    ..
+                },
+            ..
+        }) = fresh
+        {
+            report.add_rule(
+                "authority_to_fresh_wallet",
+                config.weights.authority_to_fresh_wallet,
+                &format!(
+                    "{} authority was moved around launch to {}, a wallet younger than {} days{}",
+                    authority_type,
+                    wallet,
+                    config.thresholds.wallet_young_days,
+                    partial_note(history)
+                ),
+                Some("fresh"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "authority_to_fresh_wallet"
+    }
+}
+
+pub struct MintAfterLaunchRule;
+
+impl RiskRule for MintAfterLaunchRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(history) = &token.authority_history else {
+            return;
+        };
+
+        let (count, amount) = history
+            .events
+            .iter()
+            .filter(|event| {
+                since_creation(token, event).is_some_and(|secs| secs > LAUNCH_WINDOW_SECS)
+            })
+            .filter_map(|event| match event.action {
+                AuthorityAction::MintTo { amount, .. } => Some(amount),
+                _ => None,
+            })
+            .fold((0usize, 0u128), |(count, total), amount| {
+                (count + 1, total + amount as u128)
+            });
+
+        if count > 0 {
+            let divisor = 10f64.powi(token.decimals as i32);
+            report.add_rule(
+                "mint_after_launch",
+                config.weights.mint_after_launch,
+                &format!(
+                    "Mint authority was used after launch: {} mint(s), {:.2} tokens{}",
+                    count,
+                    amount as f64 / divisor,
+                    partial_note(history)
+                ),
+                Some("minted"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "mint_after_launch"
+    }
+}
+
+pub struct PastFreezesRule;
+
+impl RiskRule for PastFreezesRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(history) = &token.authority_history else {
+            return;
+        };
+
+        // Whether each frozen account is still frozen; a thaw only counts for an
+        // account frozen earlier in the history
+        let mut still_frozen = HashMap::new();
+        for event in &history.events {
+            match event.action {
+                AuthorityAction::Freeze { account } => {
+                    still_frozen.insert(account, true);
+                }
+                AuthorityAction::Thaw { account } => {
+                    if let Some(frozen) = still_frozen.get_mut(&account) {
+                        *frozen = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+        let frozen = still_frozen.len();
+        let thawed = still_frozen.values().filter(|frozen| !**frozen).count();
+
+        if frozen > 0 {
+            report.add_rule(
+                "accounts_frozen",
+                config.weights.accounts_frozen,
+                &format!(
+                    "Freeze authority was used on {} account(s), {} thawed since{}",
+                    frozen,
+                    thawed,
+                    partial_note(history)
+                ),
+                Some("frozen"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "accounts_frozen"
+    }
+}
+
+pub struct LaunchSnipersRule;
+
+impl RiskRule for LaunchSnipersRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(launch) = &token.launch else {
+            return;
+        };
+
+        // Never claim a wider window than the launch analysis read
+        let window = config
+            .thresholds
+            .sniper_window_slots
+            .min(launch.window_slots);
+        // Unknown balances are left out, so the held share is a lower bound
+        let (wallets, bundled, unknown, held) = launch.within(window).fold(
+            (0usize, 0usize, 0usize, 0u64),
+            |(wallets, bundled, unknown, held), buyer| {
+                (
+                    wallets + 1,
+                    bundled + buyer.bundled as usize,
+                    unknown + buyer.balance.is_none() as usize,
+                    held.saturating_add(buyer.balance.unwrap_or_default()),
+                )
+            },
+        );
+        let held_pct = if token.supply == 0 {
+            0.0
+        } else {
+            held as f64 / token.supply as f64 * 100.0
+        };
+
+        report.metrics.launch = Some(LaunchMetrics {
+            sniper_wallets: wallets,
+            bundled_wallets: bundled,
+            sniper_held_pct: held_pct,
+            unknown_balances: unknown,
+            complete: launch.complete,
+        });
+
+        if held_pct > config.thresholds.sniper_supply_pct {
+            let unknown_note = if unknown > 0 {
+                format!(", not counting {} with an unknown balance", unknown)
+            } else {
+                String::new()
+            };
+            report.add_rule(
+                "snipers_hold_supply",
+                config.weights.snipers_hold_supply,
+                &format!(
+                    "{} wallet(s) bought within {} slots of creation ({} bundled with the creator) and still hold at least {:.1}% of supply{}{}",
+                    wallets,
+                    window,
+                    bundled,
+                    held_pct,
+                    unknown_note,
+                    if launch.complete { "" } else { " (partial launch data)" }
+                ),
+                Some(if bundled > 0 { "bundled" } else { "sniped" }),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "launch_snipers"
+    }
+}
@@ -0,0 +1,309 @@
+use spl_risk_core::config::Config;
+use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::token::LpLock;
+use spl_risk_core::model::{RiskReport, TokenData};
+
+/// The pool search ran; without it an empty `lp_pools` says nothing
+fn pools_searched(token: &TokenData) -> bool {
+    token.fetch_status.endpoints.contains_key("pools")
+}
+
+pub struct LpDetectionRule;
+
+impl RiskRule for LpDetectionRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        if !pools_searched(token) {
+            return;
+        }
+
+        report.flags.lp_detected = !token.lp_pools.is_empty();
+        let values: Vec<f64> = token
+            .lp_pools
+            .iter()
+            .filter_map(|pool| pool.tvl_usd)
+            .collect();
+        report.metrics.total_lp_tvl = (!values.is_empty()).then(|| values.iter().sum());
+        report.metrics.price_usd = token.lp_pools.iter().find_map(|pool| pool.price_usd);
+
+        // On the curve the liquidity is the curve's; after graduation pump.fun
+        // migrates it to PumpSwap, whose pools aren't decoded
+        if token.lp_pools.is_empty() && token.launch_stage().is_none() {
+            report.add_rule(
+                "no_lp_detected",
+                config.weights.no_lp_detected,
+                "No Raydium, Orca Whirlpool or Meteora DLMM pool found for this token",
+                Some("missing"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "lp_detection"
+    }
+}
+
+pub struct LpValueRule;
+
+impl RiskRule for LpValueRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        // A pool without a USD value would make the total a lower bound
+        let values: Option<Vec<f64>> = token.lp_pools.iter().map(|pool| pool.tvl_usd).collect();
+        let Some(values) = values.filter(|values| !values.is_empty()) else {
+            return;
+        };
+
+        let total: f64 = values.iter().sum();
+        if total < config.thresholds.low_lp_value_usd {
+            report.add_rule(
+                "low_lp_value",
+                config.weights.low_lp_value,
+                &format!(
+                    "Liquidity is worth ${:.0} across {} pool(s), below ${:.0}",
+                    total,
+                    values.len(),
+                    config.thresholds.low_lp_value_usd
+                ),
+                Some("low"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "lp_value"
+    }
+}
+
+/// Flags pools too shallow for the supply: selling a small share of it would
+/// move the price a lot
+pub struct ThinLiquidityRule;
+
+impl RiskRule for ThinLiquidityRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let sell_pct = config.thresholds.sell_size_pct;
+        let Some(impact) = token.sell_price_impact(sell_pct) else {
+            return;
+        };
+        report.metrics.sell_impact_pct = Some(impact);
+
+        if impact > config.thresholds.thin_liquidity_impact_pct {
+            report.add_rule(
+                "thin_liquidity",
+                config.weights.thin_liquidity,
+                &format!(
+                    "Selling {}% of supply would move the price {:.1}% across {} pool(s) (limit {:.0}%)",
+                    sell_pct,
+                    impact,
+                    token.lp_pools.len(),
+                    config.thresholds.thin_liquidity_impact_pct
+                ),
+                Some("thin"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "thin_liquidity"
+    }
+}
+
+/// "2 days", "5 hours", "1 year", "1.5 years"
+fn describe_duration(seconds: i64) -> String {
+    let plural =
+        |count: i64, unit: &str| format!("{} {}{}", count, unit, if count == 1 { "" } else { "s" });
+
+    let days = seconds / 86_400;
+    if days >= 365 {
+        let years = days as f64 / 365.25;
+        if (years - years.round()).abs() < 0.05 {
+            plural(years.round() as i64, "year")
+        } else {
+            format!("{:.1} years", years)
+        }
+    } else if days >= 1 {
+        plural(days, "day")
+    } else {
+        plural((seconds / 3_600).max(1), "hour")
+    }
+}
+
+/// Earliest unix time in `(now, end]` at which `secured_at` falls below
+/// `threshold`, given it is at least `threshold` at `now` and never grows
+fn first_time_below(
+    now: i64,
+    end: i64,
+    threshold: f64,
+    secured_at: impl Fn(i64) -> f64,
+) -> Option<i64> {
+    if end <= now || secured_at(end) >= threshold {
+        return None;
+    }
+
+    let (mut before, mut after) = (now, end);
+    while after - before > 1 {
+        let mid = before + (after - before) / 2;
+        if secured_at(mid) < threshold {
+            after = mid;
+        } else {
+            before = mid;
+        }
+    }
+    Some(after)
+}
+
+pub struct LpLockRule;
+
+impl RiskRule for LpLockRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        // Pools are sorted by reserve; the largest decides whether liquidity can be pulled
+        let Some(pool) = token.lp_pools.first() else {
+            return;
+        };
+        if pool.lp_token.is_none() && pool.positions.is_none() {
+            return;
+        }
+        let thresholds = &config.thresholds;
+        let now = chrono::Utc::now().timestamp();
+
+        let burned = pool
+            .lp_token
+            .as_ref()
+            .map_or(0.0, |lp_token| lp_token.burned_pct);
+        if pool.lp_token.is_some() && burned >= thresholds.lp_burned_pct {
+            report.add_rule(
+                "lp_burned",
+                config.weights.lp_burned,
+                &format!(
+                    "{:.1}% of the LP tokens of the main {} pool are burned",
+                    burned, pool.dex
+                ),
+                Some("burned"),
+            );
+            return;
+        }
+
+        // Positions can also count as locked through their owner's label alone,
+        // without known lock terms
+        let active: Vec<&LpLock> = pool.locks.iter().filter(|lock| lock.pct > 0.0).collect();
+        let (what, locked, creator_pct) = match (&pool.lp_token, &pool.positions) {
+            (Some(lp_token), _) => (
+                "LP tokens",
+                active.iter().map(|lock| lock.pct).sum::<f64>(),
+                lp_token.creator_pct,
+            ),
+            (None, Some(positions)) => ("liquidity", positions.locked_pct, positions.creator_pct),
+            (None, None) => return,
+        };
+        let secured = (burned + locked).min(100.0);
+
+        if secured < thresholds.lp_locked_pct {
+            let creator = match creator_pct {
+                Some(pct) if pct > 0.0 => format!("; the creator holds {:.1}%", pct),
+                _ => String::new(),
+            };
+            let description = if pool.lp_token.is_some() {
+                format!(
+                    "Only {:.1}% of the LP tokens of the main {} pool are burned and {:.1}% locked{}",
+                    burned, pool.dex, locked, creator
+                )
+            } else {
+                format!(
+                    "Only {:.1}% of the liquidity of the main {} pool is in locked positions{}",
+                    locked, pool.dex, creator
+                )
+            };
+            report.add_rule(
+                "lp_not_locked",
+                config.weights.lp_not_locked,
+                &description,
+                Some("unlocked"),
+            );
+            return;
+        }
+
+        // Vesting locks release liquidity step by step; the first moment too little
+        // stays burned or locked
+        let released_by = |lock: &LpLock, time: i64| lock.pct - lock.locked_pct_at(time);
+        let drop = first_time_below(
+            now,
+            active
+                .iter()
+                .map(|lock| lock.unlock_at)
+                .max()
+                .unwrap_or(now),
+            thresholds.lp_locked_pct,
+            |time| {
+                secured
+                    - active
+                        .iter()
+                        .map(|lock| released_by(lock, time))
+                        .sum::<f64>()
+            },
+        );
+        let lockers = {
+            let mut names: Vec<&str> = active.iter().map(|lock| lock.locker.as_str()).collect();
+            names.sort_unstable();
+            names.dedup();
+            if names.is_empty() {
+                "known lockers".to_string()
+            } else {
+                names.join(", ")
+            }
+        };
+
+        match drop {
+            Some(time) if time - now < thresholds.lp_unlock_soon_days as i64 * 86_400 => {
+                // Named after the lock releasing the most by then
+                let beneficiary = active
+                    .iter()
+                    .max_by(|a, b| released_by(a, time).total_cmp(&released_by(b, time)))
+                    .map(|lock| lock.beneficiary.to_string())
+                    .unwrap_or_default();
+                report.add_rule(
+                    "lp_unlock_soon",
+                    config.weights.lp_unlock_soon,
+                    &format!(
+                        "{:.1}% of the {} of the main {} pool is burned or locked in {}, but enough unlocks in {} (to {}) to leave less than {:.0}%",
+                        secured,
+                        what,
+                        pool.dex,
+                        lockers,
+                        describe_duration(time - now),
+                        beneficiary,
+                        thresholds.lp_locked_pct
+                    ),
+                    Some("unlock_soon"),
+                );
+            }
+            Some(time) => {
+                report.add_rule(
+                    "lp_locked",
+                    config.weights.lp_locked,
+                    &format!(
+                        "{:.1}% of the {} of the main {} pool is burned or locked in {}, locked for {}",
+                        secured,
+                        what,
+                        pool.dex,
+                        lockers,
+                        describe_duration(time - now)
+                    ),
+                    Some("locked"),
+                );
+            }
+            None => {
+                report.add_rule(
+                    "lp_locked",
+                    config.weights.lp_locked,
+                    &format!(
+                        "{:.1}% of the {} of the main {} pool is burned or locked in {}",
+                        secured, what, pool.dex, lockers
+                    ),
+                    Some("locked"),
+                );
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "lp_lock"
+    }
+}
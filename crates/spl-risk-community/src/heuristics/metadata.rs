@@ -1,5 +1,6 @@
 use spl_risk_core::config::Config;
 use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::known_tokens::ImpersonationKind;
 use spl_risk_core::model::{RiskReport, TokenData};
 
 pub struct VerifiedMetadataRule;
@@ -9,17 +10,26 @@ impl RiskRule for VerifiedMetadataRule {
         match &token.metadata {
             Some(metadata) => {
                 if metadata.is_verified {
+                    let description = match &metadata.collection {
+                        Some(collection) if collection.verified => {
+                            format!("Metadata is verified (collection {})", collection.key)
+                        }
+                        _ => format!(
+                            "Metadata is verified ({} verified creator(s))",
+                            metadata.verified_creators().count()
+                        ),
+                    };
                     report.add_rule(
                         "verified_metadata",
                         0, // нейтральный вес или отрицательный бонус
-                        "Metadata is verified",
+                        &description,
                         Some("verified"), // ← зелёный флаг
                     );
                 } else {
                     report.add_rule(
                         "no_verified_metadata",
                         config.weights.no_verified_metadata,
-                        "Metadata exists but is not verified",
+                        "Metadata has no verified creator or collection",
                         Some("unverified"),
                     );
                 }
@@ -39,3 +49,95 @@ impl RiskRule for VerifiedMetadataRule {
         "verified_metadata"
     }
 }
+
+pub struct MutableMetadataRule;
+
+impl RiskRule for MutableMetadataRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(metadata) = &token.metadata else {
+            return;
+        };
+
+        if metadata.is_mutable {
+            let who = metadata
+                .update_authority
+                .map(|authority| authority.to_string())
+                .unwrap_or_else(|| "the update authority".to_string());
+            report.add_rule(
+                "metadata_mutable",
+                config.weights.metadata_mutable,
+                &format!(
+                    "Metadata is mutable - {} can change name, symbol and image",
+                    who
+                ),
+                Some("mutable"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "metadata_mutable"
+    }
+}
+
+pub struct UpdateAuthorityIsMintAuthorityRule;
+
+impl RiskRule for UpdateAuthorityIsMintAuthorityRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let update_authority = token.metadata.as_ref().and_then(|m| m.update_authority);
+
+        if let (Some(update), Some(mint)) = (update_authority, token.mint_authority) {
+            if update == mint {
+                report.add_rule(
+                    "update_authority_is_mint_authority",
+                    config.weights.update_authority_is_mint_authority,
+                    "Metadata update authority is also the mint authority - one key controls supply and identity",
+                    Some("same"),
+                );
+            }
+        }
+    }
+
+    fn name(&self) -> &str {
+        "update_authority_is_mint_authority"
+    }
+}
+
+pub struct ImpersonationRule;
+
+impl RiskRule for ImpersonationRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(impersonation) = &token.impersonation else {
+            return;
+        };
+
+        let how = match impersonation.kind {
+            ImpersonationKind::Exact => "copies",
+            ImpersonationKind::Confusable => "uses look-alike characters to imitate",
+            ImpersonationKind::Similar => "is a near-copy of",
+        };
+        let field = if impersonation.field == "name" {
+            "Name"
+        } else {
+            "Symbol"
+        };
+        report.add_rule(
+            "impersonation",
+            config.weights.impersonation,
+            &format!(
+                "{} \"{}\" {} {} ({}) - the real mint is {}",
+                field,
+                impersonation.value,
+                how,
+                impersonation.symbol,
+                impersonation.name,
+                impersonation.mint
+            ),
+            Some(impersonation.kind.as_str()),
+        );
+    }
+
+    fn name(&self) -> &str {
+        "impersonation"
+    }
+}
@@ -1,40 +1,70 @@
 mod authorities;
+mod extensions;
+mod history;
+#[cfg(feature = "lp-analysis")]
+mod lp;
 mod metadata;
+mod simulation;
 mod supply;
 
 // #[cfg(test)]
 // mod tests;
 
 pub use authorities::*;
+pub use extensions::*;
+pub use history::*;
+#[cfg(feature = "lp-analysis")]
+pub use lp::*;
 pub use metadata::*;
+pub use simulation::*;
 pub use supply::*;
 
 pub fn get_community_rules() -> Vec<Box<dyn spl_risk_core::heuristics::RiskRule>> {
-    let mut rules: Vec<Box<dyn spl_risk_core::heuristics::RiskRule>> = Vec::new();
-
-    rules.push(Box::new(MintAuthorityRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(FreezeAuthorityRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(CreatorSupplyRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(CreatorIsAuthorityRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(WalletAgeRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(HolderCountRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-    rules.push(Box::new(VerifiedMetadataRule) as Box<dyn spl_risk_core::heuristics::RiskRule>);
-
-    rules
+    vec![
+        Box::new(MintAuthorityRule),
+        Box::new(FreezeAuthorityRule),
+        Box::new(NonUserHoldersRule),
+        Box::new(BondingCurveRule),
+        Box::new(CreatorSupplyRule),
+        Box::new(Top10ConcentrationRule),
+        Box::new(FundingClusterRule),
+        Box::new(CreatorIsAuthorityRule),
+        Box::new(PermanentDelegateRule),
+        Box::new(TransferHookRule),
+        Box::new(NonTransferableRule),
+        Box::new(DefaultAccountFrozenRule),
+        Box::new(PausableRule),
+        Box::new(TransferFeeRule),
+        Box::new(MintCloseAuthorityRule),
+        Box::new(ConfidentialTransferRule),
+        Box::new(TransferBlockedRule),
+        Box::new(ExcessTransferFeeRule),
+        Box::new(WalletAgeRule),
+        Box::new(HolderCountRule),
+        Box::new(VerifiedMetadataRule),
+        Box::new(MutableMetadataRule),
+        Box::new(ImpersonationRule),
+        Box::new(UpdateAuthorityIsMintAuthorityRule),
+        Box::new(AuthorityToFreshWalletRule),
+        Box::new(MintAfterLaunchRule),
+        Box::new(PastFreezesRule),
+        Box::new(LaunchSnipersRule),
+    ]
 }
 
 #[cfg(feature = "pro")]
 pub fn get_pro_rules() -> Vec<Box<dyn spl_risk_core::heuristics::RiskRule>> {
-    let mut rules: Vec<Box<dyn spl_risk_core::heuristics::RiskRule>> = get_community_rules();
+    let mut rules = get_community_rules();
 
     #[cfg(feature = "lp-analysis")]
     {
-        rules.push(Box::new(crate::heuristics::lp::LpDetectionRule)
-            as Box<dyn spl_risk_core::heuristics::RiskRule>);
-        rules.push(Box::new(crate::heuristics::lp::LpValueRule)
-            as Box<dyn spl_risk_core::heuristics::RiskRule>);
-        rules.push(Box::new(crate::heuristics::lp::LpLockRule)
-            as Box<dyn spl_risk_core::heuristics::RiskRule>);
+        let lp_rules: Vec<Box<dyn spl_risk_core::heuristics::RiskRule>> = vec![
+            Box::new(LpDetectionRule),
+            Box::new(LpValueRule),
+            Box::new(ThinLiquidityRule),
+            Box::new(LpLockRule),
+        ];
+        rules.extend(lp_rules);
     }
 
     rules
@@ -0,0 +1,60 @@
+use spl_risk_core::config::Config;
+use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::token::SimulationOutcome;
+use spl_risk_core::model::{RiskReport, TokenData};
+
+pub struct TransferBlockedRule;
+
+impl RiskRule for TransferBlockedRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(simulation) = &token.transfer_simulation else {
+            return;
+        };
+
+        if let SimulationOutcome::Failed { error, .. } = &simulation.outcome {
+            report.add_rule(
+                "transfer_blocked",
+                config.weights.transfer_blocked,
+                &format!(
+                    "Simulated transfer from top holder {} failed: {}",
+                    simulation.holder, error
+                ),
+                Some("honeypot"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "transfer_blocked"
+    }
+}
+
+pub struct ExcessTransferFeeRule;
+
+impl RiskRule for ExcessTransferFeeRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(simulation) = &token.transfer_simulation else {
+            return;
+        };
+
+        let excess = simulation.excess_fee().unwrap_or(0);
+        if excess > 0 {
+            let divisor = 10f64.powi(token.decimals as i32);
+            report.add_rule(
+                "excess_transfer_fee",
+                config.weights.excess_transfer_fee,
+                &format!(
+                    "Simulated transfer of {:.2} tokens lost {:.2} more than the declared fee of {:.2}",
+                    simulation.amount as f64 / divisor,
+                    excess as f64 / divisor,
+                    simulation.declared_fee as f64 / divisor
+                ),
+                Some("hidden_fee"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "excess_transfer_fee"
+    }
+}
@@ -1,29 +1,37 @@
 use spl_risk_core::config::Config;
 use spl_risk_core::heuristics::RiskRule;
+use spl_risk_core::model::token::LaunchStage;
 use spl_risk_core::model::{RiskReport, TokenData};
 
 pub struct CreatorSupplyRule;
 
 impl RiskRule for CreatorSupplyRule {
     fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
-        let creator_pct = token.creator_supply_percentage();
-        report.metrics.creator_supply_pct = creator_pct;
+        if let Some(creator_pct) = token.creator_supply_percentage() {
+            report.metrics.creator_supply_pct = creator_pct;
 
-        if creator_pct > config.thresholds.creator_supply_high_pct {
-            report.add_rule(
-                "creator_supply_high",
-                config.weights.creator_supply_high,
-                &format!(
-                    "Creator holds {:.1}% of supply (high concentration)",
-                    creator_pct
-                ),
-                Some("high"), // ← лучше "high"
-            );
-        } else if creator_pct < config.thresholds.supply_distributed_pct {
+            if creator_pct > config.thresholds.creator_supply_high_pct {
+                report.add_rule(
+                    "creator_supply_high",
+                    config.weights.creator_supply_high,
+                    &format!(
+                        "Creator holds {:.1}% of supply (high concentration)",
+                        creator_pct
+                    ),
+                    Some("high"), // ← лучше "high"
+                );
+                return;
+            }
+        }
+
+        let Some(top_pct) = token.top_holder_percentage() else {
+            return;
+        };
+        if top_pct < config.thresholds.supply_distributed_pct {
             report.add_rule(
                 "supply_distributed",
                 config.weights.supply_distributed,
-                &format!("Top holder has only {:.1}% (well distributed)", creator_pct),
+                &format!("Top holder has only {:.1}% (well distributed)", top_pct),
                 Some("low"), // ← лучше "low" или "distributed"
             );
         }
@@ -35,6 +43,143 @@ impl RiskRule for CreatorSupplyRule {
     }
 }
 
+/// Lists the holders left out of concentration because they are pools, burn
+/// addresses, curves or other known programs rather than users, and the unknown
+/// program accounts that stay in it
+pub struct NonUserHoldersRule;
+
+impl RiskRule for NonUserHoldersRule {
+    fn evaluate(&self, token: &TokenData, _config: &Config, report: &mut RiskReport) {
+        for holder in &token.holders {
+            let Some(label) = &holder.label else {
+                continue;
+            };
+
+            let who = match &label.name {
+                Some(name) => format!("{} ({})", name, holder.address),
+                None => format!("Program account {}", holder.address),
+            };
+            let treatment = if label.kind.is_non_user() {
+                "excluded from concentration"
+            } else {
+                "unknown program, counted in concentration"
+            };
+            report.add_rule(
+                "non_user_holder",
+                0,
+                &format!("{} holds {:.1}% - {}", who, holder.percentage, treatment),
+                Some(label.kind.as_str()),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "non_user_holders"
+    }
+}
+
+/// Explains a launchpad token's stage: before graduation the curve holds the
+/// unsold supply and all liquidity, which is neither a user nor a pool
+pub struct BondingCurveRule;
+
+impl RiskRule for BondingCurveRule {
+    fn evaluate(&self, token: &TokenData, _config: &Config, report: &mut RiskReport) {
+        let (Some(curve), Some(stage)) = (&token.bonding_curve, token.launch_stage()) else {
+            return;
+        };
+
+        let description = match stage {
+            LaunchStage::BondingCurve => {
+                let held = token
+                    .holders
+                    .iter()
+                    .find(|holder| holder.address == curve.address)
+                    .map(|holder| holder.percentage)
+                    .unwrap_or(if token.supply == 0 {
+                        0.0
+                    } else {
+                        curve.real_token_reserves as f64 / token.supply as f64 * 100.0
+                    });
+                format!(
+                    "Trading on the {} bonding curve ({:.1}% sold, {:.2} SOL raised); the curve holds {:.1}% of supply and the liquidity",
+                    curve.launchpad,
+                    curve.progress_pct,
+                    curve.real_sol_reserves as f64 / 1e9,
+                    held
+                )
+            }
+            LaunchStage::Graduated => format!(
+                "Graduated from the {} bonding curve; liquidity migrated to an AMM pool",
+                curve.launchpad
+            ),
+        };
+        report.add_rule("bonding_curve", 0, &description, Some(stage.as_str()));
+    }
+
+    fn name(&self) -> &str {
+        "bonding_curve"
+    }
+}
+
+pub struct Top10ConcentrationRule;
+
+impl RiskRule for Top10ConcentrationRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let Some(distribution) = token.distribution() else {
+            return;
+        };
+
+        if distribution.top10_pct > config.thresholds.top10_concentration_pct {
+            let nakamoto = match distribution.nakamoto {
+                Some(count) => format!(", {} wallet(s) hold over half", count),
+                None => String::new(),
+            };
+            report.add_rule(
+                "top10_concentrated",
+                config.weights.top10_concentrated,
+                &format!(
+                    "Top 10 holders own {:.1}% of supply (HHI {:.0}, Gini {:.2}{})",
+                    distribution.top10_pct, distribution.hhi, distribution.gini, nakamoto
+                ),
+                Some("high"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "top10_concentration"
+    }
+}
+
+pub struct FundingClusterRule;
+
+impl RiskRule for FundingClusterRule {
+    fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
+        let clusters = token.funding_clusters();
+        let Some(largest) = clusters.first() else {
+            return;
+        };
+
+        if largest.percentage > config.thresholds.funding_cluster_pct {
+            report.add_rule(
+                "funding_cluster",
+                config.weights.funding_cluster,
+                &format!(
+                    "{} top holders funded by {} control {:.1}% of supply",
+                    largest.members.len(),
+                    largest.funder,
+                    largest.percentage
+                ),
+                Some("clustered"),
+            );
+        }
+    }
+
+    fn name(&self) -> &str {
+        "funding_cluster"
+    }
+}
+
 pub struct HolderCountRule;
 
 impl RiskRule for HolderCountRule {
@@ -42,6 +187,11 @@ impl RiskRule for HolderCountRule {
         let holder_count = token.holder_count();
         report.metrics.holders = holder_count;
 
+        // A truncated count is only a lower bound and says nothing about "low"
+        if !token.holder_count_exact() {
+            return;
+        }
+
         if (holder_count as u64) < config.thresholds.low_holders_count {
             let description = if holder_count <= 10 {
                 format!(
@@ -78,11 +228,13 @@ pub struct WalletAgeRule;
 
 impl RiskRule for WalletAgeRule {
     fn evaluate(&self, token: &TokenData, config: &Config, report: &mut RiskReport) {
-        if let Some(creator) = token.holders.first() {
+        if let Some(creator) = &token.creator {
             if let Some(age_days) = creator.wallet_age_days {
                 report.metrics.wallet_age_days = Some(age_days);
 
-                if age_days < config.thresholds.wallet_young_days {
+                // A truncated history only dates the oldest transaction seen, so
+                // the wallet may well be older
+                if creator.wallet_age_complete && age_days < config.thresholds.wallet_young_days {
                     report.add_rule(
                         "wallet_young",
                         config.weights.wallet_young,
@@ -1,10 +1,68 @@
+use crate::error::RiskError;
+use crate::model::token::LAUNCH_WINDOW_SLOTS;
 use anyhow::Result;
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Names of the profiles compiled into the binary.
+pub const BUILTIN_PROFILES: [&str; 3] = ["conservative", "balanced", "degenerate"];
+
+/// Built-in profile used as the base when a profile file does not set `base`.
+const DEFAULT_BASE_PROFILE: &str = "balanced";
+
+/// Extensions recognised when looking up a named profile in [`Config::profiles_dir`].
+const PROFILE_EXTENSIONS: [&str; 3] = ["toml", "yaml", "yml"];
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// Profile name reported in [`RiskReport::profile`](crate::model::RiskReport)
+    #[serde(skip, default = "default_profile_name")]
+    pub name: String,
+    /// Where the weights and thresholds were loaded from
+    #[serde(skip)]
+    pub source: ProfileSource,
+
     pub weights: RiskWeights,
     pub thresholds: Thresholds,
+
+    #[serde(default)]
+    pub rpc: RpcSettings,
+}
+
+/// RPC settings that can be kept in a profile file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RpcSettings {
+    /// Endpoints used when none are given on the command line
+    #[serde(default)]
+    pub endpoints: Vec<String>,
+    /// Endpoints that must agree on the mint and metadata accounts (0 = off)
+    #[serde(default)]
+    pub quorum: usize,
+}
+
+/// Origin of a [`Config`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfileSource {
+    /// One of [`BUILTIN_PROFILES`]
+    Builtin,
+    /// A TOML/YAML profile file
+    File(PathBuf),
+    /// Built or deserialized directly in code
+    #[default]
+    Custom,
+}
+
+/// Exact identity of the profile that produced a report.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProfileIdentity {
+    pub name: String,
+    pub source: ProfileSource,
+    /// `sha256:<hex>` of the effective weights and thresholds
+    pub hash: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,11 +72,41 @@ pub struct RiskWeights {
     pub freeze_authority_active: i32,
     pub creator_supply_high: i32,
     pub creator_is_authority: i32,
+    // Used instead of `*_authority_active` when a multisig or governance program holds the authority
+    pub mint_authority_multisig: i32,
+    pub mint_authority_program: i32,
+    pub freeze_authority_multisig: i32,
+    pub freeze_authority_program: i32,
 
     // Medium
     pub wallet_young: i32,
     pub low_holders: i32,
+    pub top10_concentrated: i32,
+    pub funding_cluster: i32,
     pub no_verified_metadata: i32,
+    pub metadata_mutable: i32,
+    pub impersonation: i32,
+    pub update_authority_is_mint_authority: i32,
+
+    // Token-2022 extensions
+    pub permanent_delegate: i32,
+    pub transfer_hook: i32,
+    pub non_transferable: i32,
+    pub default_account_frozen: i32,
+    pub pausable: i32,
+    pub transfer_fee: i32,
+    pub mint_close_authority: i32,
+    pub confidential_transfer: i32,
+
+    // Authority history
+    pub authority_to_fresh_wallet: i32,
+    pub mint_after_launch: i32,
+    pub accounts_frozen: i32,
+    pub snipers_hold_supply: i32,
+
+    // Transfer simulation
+    pub transfer_blocked: i32,
+    pub excess_transfer_fee: i32,
 
     // Risk reducers
     pub mint_revoked: i32,
@@ -33,6 +121,12 @@ pub struct RiskWeights {
     pub lp_not_locked: i32,
     #[cfg(feature = "lp-analysis")]
     pub lp_burned: i32,
+    #[cfg(feature = "lp-analysis")]
+    pub lp_locked: i32,
+    #[cfg(feature = "lp-analysis")]
+    pub lp_unlock_soon: i32,
+    #[cfg(feature = "lp-analysis")]
+    pub thin_liquidity: i32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -41,31 +135,342 @@ pub struct Thresholds {
     pub supply_distributed_pct: f64,
     pub wallet_young_days: u64,
     pub low_holders_count: u64,
+    /// Share of supply held by the 10 largest user holders
+    pub top10_concentration_pct: f64,
+    /// Share of supply held by top holders funded from one wallet
+    pub funding_cluster_pct: f64,
+    /// Slots after the mint's creation in which a buyer counts as a sniper; at
+    /// most `LAUNCH_WINDOW_SLOTS`, the window the launch analysis reads
+    pub sniper_window_slots: u64,
+    /// Share of supply still held by snipers
+    pub sniper_supply_pct: f64,
 
     #[cfg(feature = "lp-analysis")]
     pub low_lp_value_usd: f64,
+    /// Share of a pool's issued LP tokens that must be burned to count as burned
+    #[cfg(feature = "lp-analysis")]
+    pub lp_burned_pct: f64,
+    /// Share of the main pool's LP tokens or liquidity that must be burned or
+    /// locked for its liquidity to count as locked
+    #[cfg(feature = "lp-analysis")]
+    pub lp_locked_pct: f64,
+    /// A lock ending within this many days counts as unlocking soon
+    #[cfg(feature = "lp-analysis")]
+    pub lp_unlock_soon_days: u64,
+    /// Share of supply sold into the pools to measure price impact
+    #[cfg(feature = "lp-analysis")]
+    pub sell_size_pct: f64,
+    /// Price impact of that sale above which liquidity counts as thin
+    #[cfg(feature = "lp-analysis")]
+    pub thin_liquidity_impact_pct: f64,
 }
 
 impl Config {
+    /// Resolve a profile by name: built-in profiles first, then
+    /// `<name>.toml`, `<name>.yaml` or `<name>.yml` in [`Config::profiles_dir`].
     pub fn from_profile(profile: &str) -> Result<Self> {
-        match profile {
-            "conservative" => Ok(Self::conservative()),
-            "balanced" => Ok(Self::balanced()),
-            "degenerate" => Ok(Self::degenerate()),
-            _ => anyhow::bail!("Unknown profile: {}", profile),
+        if let Some(config) = Self::builtin(profile) {
+            return Ok(config);
+        }
+
+        match Self::find_profile_file(profile) {
+            Some(path) => Self::from_file(&path),
+            None => {
+                let searched = Self::profiles_dir()
+                    .map(|dir| dir.display().to_string())
+                    .unwrap_or_else(|| "<no config directory>".to_string());
+                Err(RiskError::ConfigError(format!(
+                    "Unknown profile '{}': not one of {} and no profile file found in {}",
+                    profile,
+                    BUILTIN_PROFILES.join(", "),
+                    searched
+                ))
+                .into())
+            }
+        }
+    }
+
+    /// Look up a built-in profile by name.
+    pub fn builtin(profile: &str) -> Option<Self> {
+        let config = match profile {
+            "conservative" => Self::conservative(),
+            "balanced" => Self::balanced(),
+            "degenerate" => Self::degenerate(),
+            _ => return None,
+        };
+
+        Some(config)
+    }
+
+    /// Identity of this profile, with the hash computed from the current weights and thresholds.
+    pub fn identity(&self) -> ProfileIdentity {
+        ProfileIdentity {
+            name: self.name.clone(),
+            source: self.source.clone(),
+            hash: self.content_hash(),
+        }
+    }
+
+    /// `sha256:<hex>` digest of the serialized weights and thresholds.
+    ///
+    /// Two configs with the same hash score every token identically.
+    pub fn content_hash(&self) -> String {
+        let canonical = serde_json::to_vec(&(&self.weights, &self.thresholds))
+            .expect("weights and thresholds always serialize");
+        let digest = Sha256::digest(&canonical);
+
+        let hex: String = digest.iter().map(|b| format!("{:02x}", b)).collect();
+        format!("sha256:{}", hex)
+    }
+
+    /// Load a profile file (TOML or YAML, chosen by extension).
+    ///
+    /// The file names a built-in `base` profile (default: `balanced`) and
+    /// overrides any subset of its `weights` and `thresholds`. The reported
+    /// profile name is `name`, or the file stem when it is not set:
+    ///
+    /// ```toml
+    /// name = "team"
+    /// base = "conservative"
+    ///
+    /// [weights]
+    /// mint_authority_active = 40
+    ///
+    /// [thresholds]
+    /// wallet_young_days = 10
+    ///
+    /// [rpc]
+    /// endpoints = ["https://api.mainnet-beta.solana.com"]
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            RiskError::ConfigError(format!("Cannot read {}: {}", path.display(), e))
+        })?;
+
+        let format = ProfileFormat::from_path(path)?;
+        let mut config = Self::from_str_with_format(&content, format).map_err(|e| match e {
+            RiskError::ConfigError(msg) => {
+                RiskError::ConfigError(format!("{}: {}", path.display(), msg))
+            }
+            other => other,
+        })?;
+
+        if config.name.is_empty() {
+            config.name = path
+                .file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_else(default_profile_name);
         }
+        config.source = ProfileSource::File(path.to_path_buf());
+
+        Ok(config)
+    }
+
+    /// Directory searched for named profiles:
+    /// `$XDG_CONFIG_HOME/spl-risk/profiles`, falling back to `~/.config/spl-risk/profiles`.
+    pub fn profiles_dir() -> Option<PathBuf> {
+        let config_home = std::env::var_os("XDG_CONFIG_HOME")
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from)
+            .or_else(|| {
+                std::env::var_os("HOME")
+                    .or_else(|| std::env::var_os("USERPROFILE"))
+                    .map(|home| PathBuf::from(home).join(".config"))
+            })?;
+
+        Some(config_home.join("spl-risk").join("profiles"))
+    }
+
+    /// Sanity-check weights and thresholds after merging.
+    pub fn validate(&self) -> std::result::Result<(), RiskError> {
+        let weights = serde_json::to_value(&self.weights)?;
+        if let Value::Object(fields) = weights {
+            for (name, weight) in fields {
+                let weight = weight.as_i64().unwrap_or_default();
+                if !(-100..=100).contains(&weight) {
+                    return Err(RiskError::ConfigError(format!(
+                        "weights.{} = {} is outside the -100..=100 range",
+                        name, weight
+                    )));
+                }
+            }
+        }
+
+        let percentages = [
+            (
+                "creator_supply_high_pct",
+                self.thresholds.creator_supply_high_pct,
+            ),
+            (
+                "supply_distributed_pct",
+                self.thresholds.supply_distributed_pct,
+            ),
+            (
+                "top10_concentration_pct",
+                self.thresholds.top10_concentration_pct,
+            ),
+            ("funding_cluster_pct", self.thresholds.funding_cluster_pct),
+            ("sniper_supply_pct", self.thresholds.sniper_supply_pct),
+        ];
+        #[cfg(feature = "lp-analysis")]
+        let percentages = percentages.into_iter().chain([
+            ("lp_burned_pct", self.thresholds.lp_burned_pct),
+            ("lp_locked_pct", self.thresholds.lp_locked_pct),
+            ("sell_size_pct", self.thresholds.sell_size_pct),
+            (
+                "thin_liquidity_impact_pct",
+                self.thresholds.thin_liquidity_impact_pct,
+            ),
+        ]);
+        for (name, value) in percentages {
+            if !(0.0..=100.0).contains(&value) {
+                return Err(RiskError::ConfigError(format!(
+                    "thresholds.{} = {} must be a percentage between 0 and 100",
+                    name, value
+                )));
+            }
+        }
+
+        if let Some(endpoint) = self
+            .rpc
+            .endpoints
+            .iter()
+            .find(|url| !(url.starts_with("http://") || url.starts_with("https://")))
+        {
+            return Err(RiskError::ConfigError(format!(
+                "rpc.endpoints: '{}' is not an http(s) URL",
+                endpoint
+            )));
+        }
+
+        if self.thresholds.sniper_window_slots > LAUNCH_WINDOW_SLOTS {
+            return Err(RiskError::ConfigError(format!(
+                "thresholds.sniper_window_slots = {} exceeds the {} slots the launch analysis reads",
+                self.thresholds.sniper_window_slots, LAUNCH_WINDOW_SLOTS
+            )));
+        }
+
+        if self.thresholds.supply_distributed_pct > self.thresholds.creator_supply_high_pct {
+            return Err(RiskError::ConfigError(format!(
+                "thresholds.supply_distributed_pct ({}) must not exceed thresholds.creator_supply_high_pct ({})",
+                self.thresholds.supply_distributed_pct, self.thresholds.creator_supply_high_pct
+            )));
+        }
+
+        Ok(())
+    }
+
+    fn find_profile_file(name: &str) -> Option<PathBuf> {
+        // Names are looked up inside the profiles directory only; paths go through `from_file`.
+        if name.is_empty() || name.contains(['/', '\\']) {
+            return None;
+        }
+
+        let dir = Self::profiles_dir()?;
+        PROFILE_EXTENSIONS
+            .iter()
+            .map(|ext| dir.join(format!("{}.{}", name, ext)))
+            .find(|path| path.is_file())
+    }
+
+    fn from_str_with_format(
+        content: &str,
+        format: ProfileFormat,
+    ) -> std::result::Result<Self, RiskError> {
+        let overrides: Value = match format {
+            ProfileFormat::Toml => {
+                toml::from_str(content).map_err(|e| RiskError::ConfigError(e.to_string()))?
+            }
+            ProfileFormat::Yaml => {
+                serde_yaml::from_str(content).map_err(|e| RiskError::ConfigError(e.to_string()))?
+            }
+        };
+
+        let mut overrides = match overrides {
+            Value::Object(map) => map,
+            // An empty YAML document parses as null: treat it as "no overrides"
+            Value::Null => Map::new(),
+            _ => {
+                return Err(RiskError::ConfigError(
+                    "profile file must be a table of settings".to_string(),
+                ))
+            }
+        };
+
+        let name = match overrides.remove("name") {
+            None => String::new(),
+            Some(Value::String(name)) if !name.trim().is_empty() => name,
+            Some(_) => {
+                return Err(RiskError::ConfigError(
+                    "`name` must be a non-empty string".to_string(),
+                ))
+            }
+        };
+
+        let base_name = match overrides.remove("base") {
+            None => DEFAULT_BASE_PROFILE.to_string(),
+            Some(Value::String(name)) => name,
+            Some(_) => {
+                return Err(RiskError::ConfigError(
+                    "`base` must be the name of a built-in profile".to_string(),
+                ))
+            }
+        };
+
+        let base = Self::builtin(&base_name).ok_or_else(|| {
+            RiskError::ConfigError(format!(
+                "unknown base profile '{}' (expected one of {})",
+                base_name,
+                BUILTIN_PROFILES.join(", ")
+            ))
+        })?;
+
+        let mut merged = serde_json::to_value(&base)?;
+        merge_overrides(&mut merged, Value::Object(overrides), "")?;
+
+        let mut config: Config = serde_json::from_value(merged)
+            .map_err(|e| RiskError::ConfigError(format!("invalid settings: {}", e)))?;
+        config.validate()?;
+        config.name = name;
+
+        Ok(config)
     }
 
     pub fn conservative() -> Self {
         Self {
+            name: "conservative".to_string(),
+            source: ProfileSource::Builtin,
             weights: RiskWeights {
                 mint_authority_active: 35,
                 freeze_authority_active: 30,
                 creator_supply_high: 30,
                 creator_is_authority: 20,
+                mint_authority_multisig: 25,
+                mint_authority_program: 20,
+                freeze_authority_multisig: 20,
+                freeze_authority_program: 15,
                 wallet_young: 15,
                 low_holders: 10,
+                top10_concentrated: 20,
+                funding_cluster: 25,
                 no_verified_metadata: 5,
+                metadata_mutable: 10,
+                impersonation: 50,
+                update_authority_is_mint_authority: 15,
+                permanent_delegate: 45,
+                transfer_hook: 35,
+                non_transferable: 35,
+                default_account_frozen: 25,
+                pausable: 25,
+                transfer_fee: 15,
+                mint_close_authority: 10,
+                confidential_transfer: 10,
+                authority_to_fresh_wallet: 25,
+                mint_after_launch: 30,
+                accounts_frozen: 20,
+                snipers_hold_supply: 25,
+                transfer_blocked: 50,
+                excess_transfer_fee: 30,
                 mint_revoked: -25,
                 freeze_revoked: -20,
                 supply_distributed: -20,
@@ -78,29 +483,76 @@ impl Config {
                 lp_not_locked: 30,
                 #[cfg(feature = "lp-analysis")]
                 lp_burned: -25,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked: -20,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon: 30,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity: 25,
             },
             thresholds: Thresholds {
                 creator_supply_high_pct: 40.0,
                 supply_distributed_pct: 15.0,
                 wallet_young_days: 14,
                 low_holders_count: 50,
+                top10_concentration_pct: 50.0,
+                funding_cluster_pct: 15.0,
+                sniper_window_slots: 10,
+                sniper_supply_pct: 10.0,
 
                 #[cfg(feature = "lp-analysis")]
                 low_lp_value_usd: 5000.0,
+
+                #[cfg(feature = "lp-analysis")]
+                lp_burned_pct: 99.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked_pct: 95.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon_days: 30,
+                #[cfg(feature = "lp-analysis")]
+                sell_size_pct: 1.0,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity_impact_pct: 10.0,
             },
+            rpc: RpcSettings::default(),
         }
     }
 
     pub fn balanced() -> Self {
         Self {
+            name: "balanced".to_string(),
+            source: ProfileSource::Builtin,
             weights: RiskWeights {
                 mint_authority_active: 30,
                 freeze_authority_active: 25,
                 creator_supply_high: 25,
                 creator_is_authority: 15,
+                mint_authority_multisig: 18,
+                mint_authority_program: 12,
+                freeze_authority_multisig: 15,
+                freeze_authority_program: 10,
                 wallet_young: 10,
                 low_holders: 5,
+                top10_concentrated: 15,
+                funding_cluster: 20,
                 no_verified_metadata: 2,
+                metadata_mutable: 5,
+                impersonation: 40,
+                update_authority_is_mint_authority: 10,
+                permanent_delegate: 40,
+                transfer_hook: 30,
+                non_transferable: 30,
+                default_account_frozen: 20,
+                pausable: 20,
+                transfer_fee: 10,
+                mint_close_authority: 5,
+                confidential_transfer: 5,
+                authority_to_fresh_wallet: 20,
+                mint_after_launch: 25,
+                accounts_frozen: 15,
+                snipers_hold_supply: 20,
+                transfer_blocked: 45,
+                excess_transfer_fee: 25,
                 mint_revoked: -20,
                 freeze_revoked: -15,
                 supply_distributed: -15,
@@ -113,29 +565,76 @@ impl Config {
                 lp_not_locked: 25,
                 #[cfg(feature = "lp-analysis")]
                 lp_burned: -20,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked: -15,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon: 25,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity: 20,
             },
             thresholds: Thresholds {
                 creator_supply_high_pct: 50.0,
                 supply_distributed_pct: 10.0,
                 wallet_young_days: 7,
                 low_holders_count: 30,
+                top10_concentration_pct: 60.0,
+                funding_cluster_pct: 20.0,
+                sniper_window_slots: 5,
+                sniper_supply_pct: 15.0,
 
                 #[cfg(feature = "lp-analysis")]
                 low_lp_value_usd: 2000.0,
+
+                #[cfg(feature = "lp-analysis")]
+                lp_burned_pct: 95.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked_pct: 90.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon_days: 14,
+                #[cfg(feature = "lp-analysis")]
+                sell_size_pct: 1.0,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity_impact_pct: 20.0,
             },
+            rpc: RpcSettings::default(),
         }
     }
 
     pub fn degenerate() -> Self {
         Self {
+            name: "degenerate".to_string(),
+            source: ProfileSource::Builtin,
             weights: RiskWeights {
                 mint_authority_active: 20,
                 freeze_authority_active: 15,
                 creator_supply_high: 15,
                 creator_is_authority: 10,
+                mint_authority_multisig: 10,
+                mint_authority_program: 6,
+                freeze_authority_multisig: 8,
+                freeze_authority_program: 5,
                 wallet_young: 5,
                 low_holders: 3,
+                top10_concentrated: 10,
+                funding_cluster: 10,
                 no_verified_metadata: 1,
+                metadata_mutable: 2,
+                impersonation: 30,
+                update_authority_is_mint_authority: 5,
+                permanent_delegate: 30,
+                transfer_hook: 20,
+                non_transferable: 20,
+                default_account_frozen: 10,
+                pausable: 10,
+                transfer_fee: 5,
+                mint_close_authority: 3,
+                confidential_transfer: 3,
+                authority_to_fresh_wallet: 10,
+                mint_after_launch: 15,
+                accounts_frozen: 10,
+                snipers_hold_supply: 10,
+                transfer_blocked: 35,
+                excess_transfer_fee: 15,
                 mint_revoked: -15,
                 freeze_revoked: -10,
                 supply_distributed: -10,
@@ -148,16 +647,273 @@ impl Config {
                 lp_not_locked: 15,
                 #[cfg(feature = "lp-analysis")]
                 lp_burned: -15,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked: -10,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon: 15,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity: 10,
             },
             thresholds: Thresholds {
                 creator_supply_high_pct: 70.0,
                 supply_distributed_pct: 5.0,
                 wallet_young_days: 3,
                 low_holders_count: 10,
+                top10_concentration_pct: 75.0,
+                funding_cluster_pct: 30.0,
+                sniper_window_slots: 2,
+                sniper_supply_pct: 25.0,
 
                 #[cfg(feature = "lp-analysis")]
                 low_lp_value_usd: 500.0,
+
+                #[cfg(feature = "lp-analysis")]
+                lp_burned_pct: 90.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_locked_pct: 80.0,
+                #[cfg(feature = "lp-analysis")]
+                lp_unlock_soon_days: 7,
+                #[cfg(feature = "lp-analysis")]
+                sell_size_pct: 1.0,
+                #[cfg(feature = "lp-analysis")]
+                thin_liquidity_impact_pct: 40.0,
             },
+            rpc: RpcSettings::default(),
+        }
+    }
+}
+
+fn default_profile_name() -> String {
+    "custom".to_string()
+}
+
+impl fmt::Display for ProfileSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProfileSource::Builtin => write!(f, "built-in"),
+            ProfileSource::File(path) => write!(f, "file: {}", path.display()),
+            ProfileSource::Custom => write!(f, "custom"),
+        }
+    }
+}
+
+impl fmt::Display for ProfileIdentity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.name, self.source)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProfileFormat {
+    Toml,
+    Yaml,
+}
+
+impl ProfileFormat {
+    fn from_path(path: &Path) -> std::result::Result<Self, RiskError> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Ok(Self::Toml),
+            Some("yaml") | Some("yml") => Ok(Self::Yaml),
+            _ => Err(RiskError::ConfigError(format!(
+                "{}: unsupported profile format (expected .toml, .yaml or .yml)",
+                path.display()
+            ))),
+        }
+    }
+}
+
+/// Recursively apply `overlay` on top of `base`.
+///
+/// Only keys that already exist in `base` may be overridden, so typos surface as errors
+/// instead of being silently ignored. Scalars must keep their JSON kind.
+fn merge_overrides(
+    base: &mut Value,
+    overlay: Value,
+    path: &str,
+) -> std::result::Result<(), RiskError> {
+    match (base, overlay) {
+        (Value::Object(base_map), Value::Object(overlay_map)) => {
+            for (key, value) in overlay_map {
+                let field = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}.{}", path, key)
+                };
+
+                let slot = base_map
+                    .get_mut(&key)
+                    .ok_or_else(|| RiskError::ConfigError(format!("unknown field `{}`", field)))?;
+                merge_overrides(slot, value, &field)?;
+            }
+            Ok(())
         }
+        (Value::Object(_), _) => Err(RiskError::ConfigError(format!(
+            "`{}` must be a table",
+            path
+        ))),
+        (Value::Number(current), Value::Number(new)) => {
+            if current.is_f64() || !new.is_f64() {
+                *current = new;
+                Ok(())
+            } else {
+                Err(RiskError::ConfigError(format!(
+                    "`{}` must be an integer",
+                    path
+                )))
+            }
+        }
+        (Value::Number(_), _) => Err(RiskError::ConfigError(format!(
+            "`{}` must be a number",
+            path
+        ))),
+        (slot, value) => {
+            *slot = value;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_profiles_resolve() {
+        for name in BUILTIN_PROFILES {
+            assert!(Config::from_profile(name).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_toml_overrides_only_given_fields() {
+        let config = Config::from_str_with_format(
+            r#"
+            base = "conservative"
+
+            [weights]
+            mint_authority_active = 40
+
+            [thresholds]
+            wallet_young_days = 21
+            creator_supply_high_pct = 45
+            "#,
+            ProfileFormat::Toml,
+        )
+        .unwrap();
+
+        let base = Config::conservative();
+        assert_eq!(config.weights.mint_authority_active, 40);
+        assert_eq!(
+            config.weights.freeze_authority_active,
+            base.weights.freeze_authority_active
+        );
+        assert_eq!(config.thresholds.wallet_young_days, 21);
+        assert_eq!(config.thresholds.creator_supply_high_pct, 45.0);
+        assert_eq!(
+            config.thresholds.low_holders_count,
+            base.thresholds.low_holders_count
+        );
+    }
+
+    #[test]
+    fn test_yaml_defaults_to_balanced_base() {
+        let config =
+            Config::from_str_with_format("weights:\n  low_holders: 0\n", ProfileFormat::Yaml)
+                .unwrap();
+
+        assert_eq!(config.weights.low_holders, 0);
+        assert_eq!(
+            config.weights.mint_authority_active,
+            Config::balanced().weights.mint_authority_active
+        );
+    }
+
+    #[test]
+    fn test_identity_tracks_name_and_weights() {
+        let balanced = Config::balanced().identity();
+        assert_eq!(balanced.name, "balanced");
+        assert_eq!(balanced.source, ProfileSource::Builtin);
+        assert_eq!(balanced.hash, Config::balanced().content_hash());
+
+        let tweaked = Config::from_str_with_format(
+            "name = \"team\"\n[weights]\nlow_holders = 6\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(tweaked.name, "team");
+        assert_ne!(tweaked.content_hash(), balanced.hash);
+
+        let untouched =
+            Config::from_str_with_format("name = \"copy\"\n", ProfileFormat::Toml).unwrap();
+        assert_eq!(untouched.content_hash(), balanced.hash);
+    }
+
+    #[test]
+    fn test_rpc_endpoints_from_file() {
+        let config = Config::from_str_with_format(
+            "[rpc]\nendpoints = [\"https://a.example\", \"https://b.example\"]\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap();
+        assert_eq!(config.rpc.endpoints.len(), 2);
+
+        let err = Config::from_str_with_format(
+            "[rpc]\nendpoints = [\"ws://a.example\"]\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap_err();
+        assert!(err.to_string().contains("rpc.endpoints"));
+    }
+
+    #[test]
+    fn test_unknown_field_is_rejected() {
+        let err = Config::from_str_with_format(
+            "[weights]\nmint_authorty_active = 10\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("weights.mint_authorty_active"));
+    }
+
+    #[test]
+    fn test_wrong_type_is_rejected() {
+        let err = Config::from_str_with_format(
+            "[thresholds]\nwallet_young_days = 1.5\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("thresholds.wallet_young_days"));
+    }
+
+    #[test]
+    fn test_unknown_base_is_rejected() {
+        let err =
+            Config::from_str_with_format("base = \"yolo\"\n", ProfileFormat::Toml).unwrap_err();
+
+        assert!(err.to_string().contains("yolo"));
+    }
+
+    #[test]
+    fn test_validation_rejects_inverted_thresholds() {
+        let err = Config::from_str_with_format(
+            "[thresholds]\nsupply_distributed_pct = 60.0\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("supply_distributed_pct"));
+    }
+
+    #[test]
+    fn test_validation_rejects_sniper_window_past_launch_window() {
+        let err = Config::from_str_with_format(
+            "[thresholds]\nsniper_window_slots = 50\n",
+            ProfileFormat::Toml,
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("sniper_window_slots"));
     }
 }
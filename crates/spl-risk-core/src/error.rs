@@ -28,6 +28,19 @@ pub enum RiskError {
     LpAnalysisError(String),
 }
 
+impl RiskError {
+    /// The call ran out of time, either its own or the analysis deadline
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, RiskError::Timeout)
+    }
+
+    /// [`Self::is_timeout`] for an error that may wrap a `RiskError`
+    pub fn is_timeout_err(err: &anyhow::Error) -> bool {
+        err.downcast_ref::<RiskError>()
+            .is_some_and(RiskError::is_timeout)
+    }
+}
+
 impl From<serde_json::Error> for RiskError {
     fn from(err: serde_json::Error) -> Self {
         RiskError::ParseError(err.to_string())
@@ -0,0 +1,370 @@
+use crate::error::RiskError;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Well-known tokens shipped with the crate
+const BUILTIN_KNOWN_TOKENS: &str = include_str!("../data/known_tokens.toml");
+
+/// A canonical token: its symbol and name as its own metadata spells them
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KnownToken {
+    pub symbol: String,
+    pub name: String,
+}
+
+/// How a name or symbol resembles a known token's
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpersonationKind {
+    /// One or two edits away from it
+    Similar,
+    /// The same once look-alike characters, invisible characters, case and
+    /// punctuation are folded
+    Confusable,
+    /// The same text, ignoring case
+    Exact,
+}
+
+impl ImpersonationKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImpersonationKind::Similar => "similar",
+            ImpersonationKind::Confusable => "confusable",
+            ImpersonationKind::Exact => "exact",
+        }
+    }
+}
+
+/// A token whose metadata copies a known token's name or symbol
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Impersonation {
+    /// Mint of the impersonated token
+    pub mint: Pubkey,
+    pub symbol: String,
+    pub name: String,
+    /// `symbol` or `name`: which field of the analyzed token matched
+    pub field: String,
+    /// The analyzed token's value of that field
+    pub value: String,
+    pub kind: ImpersonationKind,
+}
+
+/// On-disk layout of a known token file: base58 mint -> token
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct KnownTokenFile {
+    #[serde(default)]
+    tokens: BTreeMap<String, KnownToken>,
+}
+
+/// Tokens whose name and symbol others imitate, by mint
+#[derive(Debug, Clone, Default)]
+pub struct KnownTokens {
+    tokens: HashMap<Pubkey, KnownToken>,
+}
+
+impl KnownTokens {
+    /// Tokens shipped with the crate (`data/known_tokens.toml`)
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_KNOWN_TOKENS).expect("built-in known tokens are valid")
+    }
+
+    /// Load a known token file (TOML or YAML, chosen by extension):
+    ///
+    /// ```toml
+    /// [tokens]
+    /// "EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v" = { symbol = "USDC", name = "USD Coin" }
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, RiskError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            RiskError::ConfigError(format!("Cannot read {}: {}", path.display(), e))
+        })?;
+
+        let registry = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .map_err(|e| RiskError::ConfigError(e.to_string()))
+                .and_then(Self::from_token_file),
+            _ => Err(RiskError::ConfigError(
+                "unsupported known token file format (expected .toml, .yaml or .yml)".to_string(),
+            )),
+        };
+
+        registry.map_err(|e| match e {
+            RiskError::ConfigError(msg) => {
+                RiskError::ConfigError(format!("{}: {}", path.display(), msg))
+            }
+            other => other,
+        })
+    }
+
+    fn from_toml(content: &str) -> Result<Self, RiskError> {
+        toml::from_str(content)
+            .map_err(|e| RiskError::ConfigError(e.to_string()))
+            .and_then(Self::from_token_file)
+    }
+
+    fn from_token_file(file: KnownTokenFile) -> Result<Self, RiskError> {
+        let tokens = file
+            .tokens
+            .into_iter()
+            .map(|(mint, token)| {
+                Pubkey::from_str(&mint)
+                    .map(|mint| (mint, token))
+                    .map_err(|_| RiskError::ConfigError(format!("invalid mint {}", mint)))
+            })
+            .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(Self { tokens })
+    }
+
+    /// Add `other`'s tokens, replacing existing ones for the same mint
+    pub fn extend(&mut self, other: KnownTokens) {
+        self.tokens.extend(other.tokens);
+    }
+
+    pub fn get(&self, mint: &Pubkey) -> Option<&KnownToken> {
+        self.tokens.get(mint)
+    }
+
+    /// The known token `mint`'s name or symbol imitates most closely; `None` for
+    /// the known mints themselves
+    pub fn impersonated(&self, mint: &Pubkey, name: &str, symbol: &str) -> Option<Impersonation> {
+        if self.tokens.contains_key(mint) {
+            return None;
+        }
+
+        let mut best: Option<Impersonation> = None;
+        for (known_mint, known) in &self.tokens {
+            for (field, value, known_value) in [
+                ("symbol", symbol, known.symbol.as_str()),
+                ("name", name, known.name.as_str()),
+            ] {
+                let Some(kind) = resemblance(value, known_value) else {
+                    continue;
+                };
+                // Strongest kind wins; ties go to the lowest mint so the result
+                // doesn't depend on map order
+                let better = best.as_ref().map_or(true, |best| {
+                    (kind, std::cmp::Reverse(known_mint))
+                        > (best.kind, std::cmp::Reverse(&best.mint))
+                });
+                if better {
+                    best = Some(Impersonation {
+                        mint: *known_mint,
+                        symbol: known.symbol.clone(),
+                        name: known.name.clone(),
+                        field: field.to_string(),
+                        value: value.trim().to_string(),
+                        kind,
+                    });
+                }
+            }
+        }
+        best
+    }
+}
+
+/// How `value` resembles `known`. Edits are only counted against skeletons of
+/// 5+ characters (2 edits from 9): shorter tickers legitimately differ by one letter.
+fn resemblance(value: &str, known: &str) -> Option<ImpersonationKind> {
+    let value_skeleton = skeleton(value);
+    let known_skeleton = skeleton(known);
+    if value_skeleton.is_empty() || known_skeleton.is_empty() {
+        return None;
+    }
+
+    if value.trim().to_lowercase() == known.trim().to_lowercase() {
+        return Some(ImpersonationKind::Exact);
+    }
+    if value_skeleton == known_skeleton {
+        return Some(ImpersonationKind::Confusable);
+    }
+
+    let max_edits = match known_skeleton.len() {
+        0..=4 => 0,
+        5..=8 => 1,
+        _ => 2,
+    };
+    (edit_distance(&value_skeleton, &known_skeleton) <= max_edits)
+        .then_some(ImpersonationKind::Similar)
+}
+
+/// Fold `text` to what it looks like: lowercase ASCII letters and digits, with
+/// Cyrillic and Greek look-alikes, fullwidth forms and digits that pass for
+/// letters mapped to the letter, and invisible characters, combining marks,
+/// spaces and punctuation dropped
+fn skeleton(text: &str) -> Vec<char> {
+    let mut folded = String::new();
+    for c in text.chars().flat_map(char::to_lowercase) {
+        let c = match c {
+            // Fullwidth ASCII
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            // Cyrillic
+            'а' => 'a',
+            'в' | 'ь' => 'b',
+            'е' | 'ё' => 'e',
+            'к' => 'k',
+            'м' => 'm',
+            'н' => 'h',
+            'о' => 'o',
+            'р' => 'p',
+            'с' => 'c',
+            'т' => 't',
+            'у' => 'y',
+            'х' => 'x',
+            'і' | 'ї' => 'i',
+            'ј' => 'j',
+            'ѕ' => 's',
+            'ԁ' => 'd',
+            'ԛ' => 'q',
+            'ԝ' => 'w',
+            'ӏ' => 'l',
+            // Greek
+            'α' => 'a',
+            'β' => 'b',
+            'ε' => 'e',
+            'η' => 'n',
+            'ι' => 'i',
+            'κ' => 'k',
+            'μ' => 'm',
+            'ν' => 'v',
+            'ο' => 'o',
+            'ρ' => 'p',
+            'τ' => 't',
+            'υ' => 'u',
+            'χ' => 'x',
+            'ζ' => 'z',
+            'ω' => 'w',
+            // Latin look-alikes
+            'ı' => 'i',
+            'ℓ' => 'l',
+            '$' => 's',
+            '|' | '!' => 'l',
+            c => c,
+        };
+        // Digits and letters that read the same
+        let c = match c {
+            '0' => 'o',
+            '1' | 'i' => 'l',
+            '5' => 's',
+            c => c,
+        };
+        if c.is_ascii_alphanumeric() {
+            folded.push(c);
+        }
+    }
+
+    folded
+        .replace("rn", "m")
+        .replace("vv", "w")
+        .chars()
+        .collect()
+}
+
+/// Optimal string alignment distance: insertions, deletions, substitutions and
+/// swaps of adjacent characters
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut rows = vec![vec![0usize; b.len() + 1]; a.len() + 1];
+    rows[0] = (0..=b.len()).collect();
+    for (i, row) in rows.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let mut distance = (rows[i - 1][j] + 1)
+                .min(rows[i][j - 1] + 1)
+                .min(rows[i - 1][j - 1] + cost);
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distance = distance.min(rows[i - 2][j - 2] + 1);
+            }
+            rows[i][j] = distance;
+        }
+    }
+    rows[a.len()][b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usdc() -> Pubkey {
+        Pubkey::from_str("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v").unwrap()
+    }
+
+    #[test]
+    fn test_detects_impersonation() {
+        let registry = KnownTokens::builtin();
+        let fake = Pubkey::new_unique();
+        let kind = |name: &str, symbol: &str| {
+            registry
+                .impersonated(&fake, name, symbol)
+                .map(|hit| (hit.mint, hit.field, hit.kind))
+        };
+
+        assert_eq!(
+            kind("Totally Legit", "USDC"),
+            Some((usdc(), "symbol".to_string(), ImpersonationKind::Exact))
+        );
+        // Cyrillic С, and a zero-width space
+        assert_eq!(
+            kind("Some Token", "USDС"),
+            Some((usdc(), "symbol".to_string(), ImpersonationKind::Confusable))
+        );
+        assert_eq!(
+            kind("U\u{200b}SD C0in", "XYZ"),
+            Some((usdc(), "name".to_string(), ImpersonationKind::Confusable))
+        );
+        // An exact symbol outranks a look-alike name
+        assert_eq!(
+            kind("U$D Coin", "usdc").map(|(_, field, kind)| (field, kind)),
+            Some(("symbol".to_string(), ImpersonationKind::Exact))
+        );
+        assert_eq!(
+            kind("dogwifhats", "DWH").map(|(_, _, kind)| kind),
+            Some(ImpersonationKind::Similar)
+        );
+        assert_eq!(
+            kind("Pyth Netwrok", "PN").map(|(_, _, kind)| kind),
+            Some(ImpersonationKind::Similar)
+        );
+
+        // Short tickers a letter apart are different tokens
+        assert_eq!(kind("Sky Dollar", "USDS"), None);
+        assert_eq!(kind("", ""), None);
+        // The real mint doesn't impersonate itself
+        assert_eq!(registry.impersonated(&usdc(), "USD Coin", "USDC"), None);
+    }
+
+    #[test]
+    fn test_user_tokens_extend_and_override() {
+        let mint = Pubkey::new_unique();
+        let user = KnownTokens::from_toml(&format!(
+            "[tokens]\n\"{}\" = {{ symbol = \"ACME\", name = \"Acme Token\" }}\n\"EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v\" = {{ symbol = \"USDC\", name = \"USDC\" }}\n",
+            mint
+        ))
+        .unwrap();
+
+        let mut registry = KnownTokens::builtin();
+        registry.extend(user);
+
+        let hit = registry
+            .impersonated(&Pubkey::new_unique(), "ACME", "ACME")
+            .unwrap();
+        assert_eq!((hit.mint, hit.kind), (mint, ImpersonationKind::Exact));
+        assert_eq!(
+            registry.get(&usdc()).map(|token| token.name.as_str()),
+            Some("USDC")
+        );
+
+        let err =
+            KnownTokens::from_toml("[tokens]\n\"not-a-key\" = { symbol = \"X\", name = \"X\" }\n")
+                .unwrap_err();
+        assert!(err.to_string().contains("invalid mint not-a-key"));
+    }
+}
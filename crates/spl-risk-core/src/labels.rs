@@ -0,0 +1,205 @@
+use crate::error::RiskError;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use std::str::FromStr;
+
+/// Labels shipped with the crate
+const BUILTIN_LABELS: &str = include_str!("../data/labels.toml");
+
+/// What a non-user holder is
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HolderKind {
+    /// AMM or CLMM pool vault
+    Pool,
+    /// Tokens nobody can move
+    Burn,
+    /// Centralized exchange wallet
+    Exchange,
+    /// Launchpad bonding curve
+    Curve,
+    /// Vesting or lock escrow
+    Locker,
+    /// Any other program-owned account or PDA listed in the registry
+    Program,
+    /// Program-owned account or PDA the registry doesn't know. Anyone can park
+    /// supply in a PDA of their own program, so these still count as users.
+    UnknownProgram,
+}
+
+impl HolderKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HolderKind::Pool => "pool",
+            HolderKind::Burn => "burn",
+            HolderKind::Exchange => "exchange",
+            HolderKind::Curve => "curve",
+            HolderKind::Locker => "locker",
+            HolderKind::Program => "program",
+            HolderKind::UnknownProgram => "unknown_program",
+        }
+    }
+
+    /// Whether holders of this kind are left out of supply concentration
+    pub fn is_non_user(&self) -> bool {
+        *self != HolderKind::UnknownProgram
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HolderLabel {
+    pub kind: HolderKind,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+/// On-disk layout of a label file: base58 address -> label
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LabelFile {
+    #[serde(default)]
+    addresses: BTreeMap<String, HolderLabel>,
+    #[serde(default)]
+    programs: BTreeMap<String, HolderLabel>,
+}
+
+/// Known non-user holders: token account owners labeled by address, or by the
+/// program that owns the owner account.
+#[derive(Debug, Clone, Default)]
+pub struct LabelRegistry {
+    addresses: HashMap<Pubkey, HolderLabel>,
+    programs: HashMap<Pubkey, HolderLabel>,
+}
+
+impl LabelRegistry {
+    /// Labels shipped with the crate (`data/labels.toml`)
+    pub fn builtin() -> Self {
+        Self::from_toml(BUILTIN_LABELS).expect("built-in labels are valid")
+    }
+
+    /// Load a label file (TOML or YAML, chosen by extension):
+    ///
+    /// ```toml
+    /// [addresses]
+    /// "1nc1nerator11111111111111111111111111111111" = { kind = "burn", name = "Incinerator" }
+    ///
+    /// [programs]
+    /// "6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P" = { kind = "curve", name = "pump.fun" }
+    /// ```
+    pub fn from_file(path: &Path) -> Result<Self, RiskError> {
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            RiskError::ConfigError(format!("Cannot read {}: {}", path.display(), e))
+        })?;
+
+        let registry = match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("yaml") | Some("yml") => serde_yaml::from_str(&content)
+                .map_err(|e| RiskError::ConfigError(e.to_string()))
+                .and_then(Self::from_label_file),
+            _ => Err(RiskError::ConfigError(
+                "unsupported label file format (expected .toml, .yaml or .yml)".to_string(),
+            )),
+        };
+
+        registry.map_err(|e| match e {
+            RiskError::ConfigError(msg) => {
+                RiskError::ConfigError(format!("{}: {}", path.display(), msg))
+            }
+            other => other,
+        })
+    }
+
+    fn from_toml(content: &str) -> Result<Self, RiskError> {
+        toml::from_str(content)
+            .map_err(|e| RiskError::ConfigError(e.to_string()))
+            .and_then(Self::from_label_file)
+    }
+
+    fn from_label_file(file: LabelFile) -> Result<Self, RiskError> {
+        let parse = |entries: BTreeMap<String, HolderLabel>| {
+            entries
+                .into_iter()
+                .map(|(address, label)| {
+                    Pubkey::from_str(&address)
+                        .map(|address| (address, label))
+                        .map_err(|_| RiskError::ConfigError(format!("invalid address {}", address)))
+                })
+                .collect::<Result<HashMap<_, _>, _>>()
+        };
+
+        Ok(Self {
+            addresses: parse(file.addresses)?,
+            programs: parse(file.programs)?,
+        })
+    }
+
+    /// Add `other`'s labels, replacing existing ones for the same address
+    pub fn extend(&mut self, other: LabelRegistry) {
+        self.addresses.extend(other.addresses);
+        self.programs.extend(other.programs);
+    }
+
+    /// Label of a token account owner
+    pub fn address(&self, address: &Pubkey) -> Option<&HolderLabel> {
+        self.addresses.get(address)
+    }
+
+    /// Label for owner accounts held by `program`
+    pub fn program(&self, program: &Pubkey) -> Option<&HolderLabel> {
+        self.programs.get(program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_labels_parse() {
+        let registry = LabelRegistry::builtin();
+
+        let incinerator = Pubkey::from_str("1nc1nerator11111111111111111111111111111111").unwrap();
+        assert_eq!(
+            registry.address(&incinerator).map(|label| label.kind),
+            Some(HolderKind::Burn)
+        );
+
+        let pump = Pubkey::from_str("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P").unwrap();
+        assert_eq!(
+            registry.program(&pump).map(|label| label.kind),
+            Some(HolderKind::Curve)
+        );
+    }
+
+    #[test]
+    fn test_user_labels_extend_and_override() {
+        let wallet = Pubkey::new_unique();
+        let user = LabelRegistry::from_toml(&format!(
+            "[addresses]\n\"{}\" = {{ kind = \"exchange\" }}\n\"1nc1nerator11111111111111111111111111111111\" = {{ kind = \"locker\", name = \"Mine\" }}\n",
+            wallet
+        ))
+        .unwrap();
+
+        let mut registry = LabelRegistry::builtin();
+        registry.extend(user);
+
+        assert_eq!(
+            registry.address(&wallet),
+            Some(&HolderLabel {
+                kind: HolderKind::Exchange,
+                name: None,
+            })
+        );
+        let incinerator = Pubkey::from_str("1nc1nerator11111111111111111111111111111111").unwrap();
+        assert_eq!(
+            registry.address(&incinerator).map(|label| label.kind),
+            Some(HolderKind::Locker)
+        );
+
+        let err = LabelRegistry::from_toml("[addresses]\n\"not-a-key\" = { kind = \"pool\" }\n")
+            .unwrap_err();
+        assert!(err.to_string().contains("invalid address not-a-key"));
+    }
+}
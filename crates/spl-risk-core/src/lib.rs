@@ -1,6 +1,8 @@
 pub mod config;
 pub mod error;
 pub mod heuristics;
+pub mod known_tokens;
+pub mod labels;
 pub mod model;
 pub mod provider;
 pub mod scoring;
@@ -0,0 +1,192 @@
+use crate::model::token::TokenHolder;
+use serde::{Deserialize, Serialize};
+use solana_sdk::pubkey::Pubkey;
+use std::collections::{BTreeMap, HashSet};
+
+/// How supply is spread across the holders that were seen.
+///
+/// Computed over user holders only (labeled pools, burn addresses and programs are
+/// left out). Shares are percentages of total supply; without a deep holder scan
+/// only the largest accounts are seen, so `gini` and `hhi` describe those alone.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HolderDistribution {
+    pub top5_pct: f64,
+    pub top10_pct: f64,
+    pub top20_pct: f64,
+    /// Herfindahl-Hirschman index: sum of squared percentage shares (0-10000)
+    pub hhi: f64,
+    /// Gini coefficient of the balances, 0 (equal) to 1 (one holder has everything)
+    pub gini: f64,
+    /// Fewest holders that together hold more than half of supply; `None` when
+    /// the holders seen don't reach half
+    pub nakamoto: Option<usize>,
+}
+
+impl HolderDistribution {
+    /// `holders` must be sorted largest first; `None` when there are none
+    pub fn from_holders<'a>(holders: impl IntoIterator<Item = &'a TokenHolder>) -> Option<Self> {
+        let holders: Vec<&TokenHolder> = holders.into_iter().collect();
+        if holders.is_empty() {
+            return None;
+        }
+
+        let top = |n: usize| holders.iter().take(n).map(|h| h.percentage).sum::<f64>();
+
+        let hhi = holders.iter().map(|h| h.percentage * h.percentage).sum();
+
+        let mut cumulative = 0.0;
+        let nakamoto = holders.iter().position(|h| {
+            cumulative += h.percentage;
+            cumulative > 50.0
+        });
+
+        Some(Self {
+            top5_pct: top(5),
+            top10_pct: top(10),
+            top20_pct: top(20),
+            hhi,
+            gini: gini(holders.iter().map(|h| h.amount)),
+            nakamoto: nakamoto.map(|index| index + 1),
+        })
+    }
+}
+
+/// User holders whose first SOL came from the same wallet
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FundingCluster {
+    pub funder: Pubkey,
+    /// Largest first; includes the funder when it holds the token and wasn't
+    /// itself funded by a known wallet
+    pub members: Vec<Pubkey>,
+    pub amount: u64,
+    pub percentage: f64,
+}
+
+impl FundingCluster {
+    /// Clusters of two or more holders, largest share first. Each holder joins at
+    /// most one cluster: its funder's, or its own when it funded other holders.
+    pub fn find<'a>(holders: impl IntoIterator<Item = &'a TokenHolder>) -> Vec<Self> {
+        let holders: Vec<&TokenHolder> = holders.into_iter().collect();
+        let funders: HashSet<Pubkey> = holders.iter().filter_map(|h| h.funder).collect();
+
+        let mut clusters: BTreeMap<Pubkey, FundingCluster> = BTreeMap::new();
+        for holder in holders {
+            let key = match holder.funder {
+                Some(funder) => funder,
+                None if funders.contains(&holder.address) => holder.address,
+                None => continue,
+            };
+
+            let cluster = clusters.entry(key).or_insert_with(|| FundingCluster {
+                funder: key,
+                members: Vec::new(),
+                amount: 0,
+                percentage: 0.0,
+            });
+            cluster.members.push(holder.address);
+            cluster.amount = cluster.amount.saturating_add(holder.amount);
+            cluster.percentage += holder.percentage;
+        }
+
+        let mut clusters: Vec<FundingCluster> = clusters
+            .into_values()
+            .filter(|cluster| cluster.members.len() >= 2)
+            .collect();
+        clusters.sort_by_key(|cluster| std::cmp::Reverse(cluster.amount));
+        clusters
+    }
+}
+
+fn gini(amounts: impl Iterator<Item = u64>) -> f64 {
+    let mut amounts: Vec<f64> = amounts.map(|amount| amount as f64).collect();
+    amounts.sort_by(f64::total_cmp);
+
+    let n = amounts.len() as f64;
+    let total: f64 = amounts.iter().sum();
+    if amounts.is_empty() || total == 0.0 {
+        return 0.0;
+    }
+
+    // G = 2 * sum(i * x_i) / (n * sum(x)) - (n + 1) / n, with x ascending and i from 1
+    let weighted: f64 = amounts
+        .iter()
+        .enumerate()
+        .map(|(i, amount)| (i + 1) as f64 * amount)
+        .sum();
+    2.0 * weighted / (n * total) - (n + 1.0) / n
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn holders(amounts: &[u64], supply: u64) -> Vec<TokenHolder> {
+        amounts
+            .iter()
+            .map(|&amount| TokenHolder {
+                address: Pubkey::new_unique(),
+                amount,
+                percentage: amount as f64 / supply as f64 * 100.0,
+                wallet_age_days: None,
+                wallet_age_complete: false,
+                label: None,
+                funder: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_equal_holders() {
+        let holders = holders(&[10; 10], 100);
+        let distribution = HolderDistribution::from_holders(&holders).unwrap();
+
+        assert!((distribution.top5_pct - 50.0).abs() < 1e-9);
+        assert!((distribution.top10_pct - 100.0).abs() < 1e-9);
+        assert!((distribution.top20_pct - 100.0).abs() < 1e-9);
+        assert!((distribution.hhi - 1000.0).abs() < 1e-9);
+        assert!(distribution.gini.abs() < 1e-9);
+        assert_eq!(distribution.nakamoto, Some(6));
+    }
+
+    #[test]
+    fn test_concentrated_holders() {
+        let holders = holders(&[90, 4, 3, 2, 1], 100);
+        let distribution = HolderDistribution::from_holders(&holders).unwrap();
+
+        assert!((distribution.hhi - 8130.0).abs() < 1e-9);
+        assert!((distribution.gini - 0.72).abs() < 1e-9);
+        assert_eq!(distribution.nakamoto, Some(1));
+
+        // Holders seen hold less than half of supply
+        let holders = self::holders(&[20, 10], 100);
+        let distribution = HolderDistribution::from_holders(&holders).unwrap();
+        assert_eq!(distribution.nakamoto, None);
+
+        assert_eq!(HolderDistribution::from_holders(&[]), None);
+    }
+
+    #[test]
+    fn test_funding_clusters() {
+        let mut holders = holders(&[30, 20, 10, 5, 5], 100);
+        let funder = Pubkey::new_unique();
+        holders[1].funder = Some(funder);
+        holders[3].funder = Some(funder);
+        // A holder that funded another one clusters with it
+        holders[4].funder = Some(holders[0].address);
+        // A lone funded holder is no cluster
+        holders[2].funder = Some(Pubkey::new_unique());
+
+        let clusters = FundingCluster::find(&holders);
+        assert_eq!(clusters.len(), 2);
+
+        assert_eq!(clusters[0].funder, holders[0].address);
+        assert_eq!(
+            clusters[0].members,
+            vec![holders[0].address, holders[4].address]
+        );
+        assert!((clusters[0].percentage - 35.0).abs() < 1e-9);
+
+        assert_eq!(clusters[1].funder, funder);
+        assert_eq!(clusters[1].amount, 25);
+    }
+}
@@ -1,3 +1,4 @@
+pub mod distribution;
 pub mod report;
 pub mod token;
 pub use report::RiskReport;
@@ -1,36 +1,79 @@
+use crate::config::ProfileIdentity;
+use crate::known_tokens::Impersonation;
+use crate::model::distribution::{FundingCluster, HolderDistribution};
+#[cfg(feature = "lp-analysis")]
+use crate::model::token::LiquidityPool;
+use crate::model::token::{AuthorityEvent, BondingCurve, TransferSimulation};
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskReport {
     pub mint: Pubkey,
     pub risk_score: u32,
     pub confidence_score: f32, // 0.0-1.0 - how complete is the data
-    pub profile: String,
+    pub profile: ProfileIdentity,
     pub flags: RiskFlags,
     pub metrics: RiskMetrics,
     pub breakdown: Vec<RiskBreakdown>,
     pub summary: String,
     pub warnings: Vec<String>,
     pub data_sources: DataSources,
+    /// Authority timeline of the mint, oldest first; empty when it wasn't fetched
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub authority_history: Vec<AuthorityEvent>,
+    /// Top holders funded by a common wallet, largest share first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub funding_clusters: Vec<FundingCluster>,
+    /// Simulated transfer out of a top holder's account, when it was run
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_simulation: Option<TransferSimulation>,
+    /// Launchpad bonding curve of the mint, when it has one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bonding_curve: Option<BondingCurve>,
+    /// Well-known token whose name or symbol this one imitates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonation: Option<Impersonation>,
+    /// Pools trading the token, largest reserve first
+    #[cfg(feature = "lp-analysis")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub lp_pools: Vec<LiquidityPool>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DataSources {
     pub rpc: String,        // "ok", "timeout", "error"
     pub metadata: String,   // "ok", "cached", "missing", "error"
-    pub holders: String,    // "ok", "partial", "cached", "timeout"
-    pub wallet_age: String, // "ok", "cached", "missing"
+    pub holders: String,    // "exact", "truncated", "partial", "cached", "timeout"
+    pub wallet_age: String, // "ok", "cached", "truncated", "missing"
+    #[serde(default)]
+    pub creation_time: String, // "ok", "truncated", "missing"
 
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cached_at: Option<HashMap<String, String>>, // timestamp for cached data
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub endpoints: Option<BTreeMap<String, String>>, // RPC endpoint per datum
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_source: Option<String>, // "metaplex", "token_2022"; None when metadata is missing
+
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quorum: Option<String>, // "ok", "partial", "disagree"; None when quorum mode is off
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authority_history: Option<String>, // "ok", "partial"; None when not fetched
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskFlags {
     pub mint_authority: bool,
     pub freeze_authority: bool,
+    #[serde(default)]
+    pub permanent_delegate: bool,
+    #[serde(default)]
+    pub transfer_hook: bool,
 
     #[cfg(feature = "lp-analysis")]
     pub lp_detected: bool,
@@ -38,15 +81,46 @@ pub struct RiskFlags {
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RiskMetrics {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Pubkey>, // fee payer of the mint's initialization
     pub creator_supply_pct: f64,
     pub wallet_age_days: Option<u64>,
     pub holders: usize,
+    #[serde(default)]
+    pub holders_exact: bool, // false = `holders` is a lower bound (largest accounts only)
     pub decimals: Option<u8>,
     pub total_supply: Option<u64>,
     pub top_holder_pct: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<HolderDistribution>, // top-N shares, HHI, Gini, Nakamoto
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>, // Token-2022 mint extensions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch: Option<LaunchMetrics>, // early buyers, with launch analysis only
 
     #[cfg(feature = "lp-analysis")]
     pub total_lp_tvl: Option<f64>,
+    #[cfg(feature = "lp-analysis")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_usd: Option<f64>, // from the main pool with a USD-priced quote
+    #[cfg(feature = "lp-analysis")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sell_impact_pct: Option<f64>, // price drop from selling thresholds.sell_size_pct of supply
+}
+
+/// Wallets that bought within `sniper_window_slots` of the mint's creation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LaunchMetrics {
+    pub sniper_wallets: usize,
+    /// Snipers that bought in a slot where the creator also transacted
+    pub bundled_wallets: usize,
+    /// Share of supply the snipers still hold
+    pub sniper_held_pct: f64,
+    /// Snipers whose current balance couldn't be read, left out of `sniper_held_pct`
+    #[serde(default)]
+    pub unknown_balances: usize,
+    #[serde(default)]
+    pub complete: bool, // false = launch transactions or buyer balances were capped or failed to load
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -58,7 +132,7 @@ pub struct RiskBreakdown {
 }
 
 impl RiskReport {
-    pub fn new(mint: Pubkey, profile: String) -> Self {
+    pub fn new(mint: Pubkey, profile: ProfileIdentity) -> Self {
         Self {
             mint,
             risk_score: 0,
@@ -67,19 +141,30 @@ impl RiskReport {
             flags: RiskFlags {
                 mint_authority: false,
                 freeze_authority: false,
+                permanent_delegate: false,
+                transfer_hook: false,
                 #[cfg(feature = "lp-analysis")]
                 lp_detected: false,
             },
             metrics: RiskMetrics {
+                creator: None,
                 creator_supply_pct: 0.0,
                 wallet_age_days: None,
                 holders: 0,
+                holders_exact: false,
                 decimals: None,     // ← добавили
                 total_supply: None, // ← добавили
                 top_holder_pct: None,
+                distribution: None,
+                extensions: Vec::new(),
+                launch: None,
 
                 #[cfg(feature = "lp-analysis")]
                 total_lp_tvl: None,
+                #[cfg(feature = "lp-analysis")]
+                price_usd: None,
+                #[cfg(feature = "lp-analysis")]
+                sell_impact_pct: None,
             },
             breakdown: Vec::new(),
             summary: String::new(),
@@ -89,8 +174,20 @@ impl RiskReport {
                 metadata: "ok".to_string(),
                 holders: "ok".to_string(),
                 wallet_age: "ok".to_string(),
+                creation_time: "ok".to_string(),
                 cached_at: None,
+                endpoints: None,
+                metadata_source: None,
+                quorum: None,
+                authority_history: None,
             },
+            authority_history: Vec::new(),
+            funding_clusters: Vec::new(),
+            transfer_simulation: None,
+            bonding_curve: None,
+            impersonation: None,
+            #[cfg(feature = "lp-analysis")]
+            lp_pools: Vec::new(),
         }
     }
 
@@ -114,6 +211,8 @@ impl RiskReport {
         // Reduce confidence based on data quality
         if self.data_sources.holders == "partial" || self.data_sources.holders == "timeout" {
             confidence *= 0.7; // -30% for partial holder data
+        } else if self.data_sources.holders == "truncated" {
+            confidence *= 0.95; // -5%: only the largest holders were seen
         }
 
         if self.data_sources.metadata == "cached" {
@@ -124,12 +223,28 @@ impl RiskReport {
 
         if self.data_sources.wallet_age == "missing" {
             confidence *= 0.92; // -8% for missing wallet age
+        } else if self.data_sources.wallet_age == "truncated" {
+            confidence *= 0.96; // -4%: history budget ran out, age is a lower bound
+        }
+
+        if self.data_sources.creation_time == "truncated" {
+            confidence *= 0.95; // -5%: first transaction not reached, creator may be unknown
         }
 
         if self.data_sources.rpc == "timeout" || self.data_sources.rpc == "error" {
             confidence *= 0.6; // -40% for RPC issues
         }
 
+        if self.data_sources.authority_history.as_deref() == Some("partial") {
+            confidence *= 0.97; // -3%: authority timeline may have gaps
+        }
+
+        match self.data_sources.quorum.as_deref() {
+            Some("disagree") => confidence *= 0.5, // -50%: endpoints returned conflicting state
+            Some("partial") => confidence *= 0.9,  // -10%: not enough endpoints answered
+            _ => {}
+        }
+
         // Community edition без LP analysis
         #[cfg(not(feature = "lp-analysis"))]
         {
@@ -165,6 +280,11 @@ impl RiskReport {
                 .push_str(" Token owner retains destructive privileges.");
         }
 
+        if self.flags.permanent_delegate {
+            self.summary
+                .push_str(" A permanent delegate can move or burn any holder's tokens.");
+        }
+
         // Add warning if holder data is incomplete
         if self.metrics.holders == 0
             && (self.data_sources.holders == "partial" || self.data_sources.holders == "timeout")
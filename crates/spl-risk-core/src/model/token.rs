@@ -1,5 +1,9 @@
+use crate::known_tokens::Impersonation;
+use crate::labels::HolderLabel;
+use crate::model::distribution::{FundingCluster, HolderDistribution};
 use serde::{Deserialize, Serialize};
 use solana_sdk::pubkey::Pubkey;
+use std::collections::BTreeMap;
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TokenData {
@@ -8,9 +12,45 @@ pub struct TokenData {
     pub decimals: u8,
     pub mint_authority: Option<Pubkey>,
     pub freeze_authority: Option<Pubkey>,
+    /// What controls each authority; `None` when it is revoked or its account
+    /// could not be read
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mint_authority_kind: Option<AuthorityKind>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub freeze_authority_kind: Option<AuthorityKind>,
+    /// Token-2022 mint extensions; empty for legacy SPL tokens
+    #[serde(default)]
+    pub extensions: Vec<MintExtension>,
     pub metadata: Option<TokenMetadata>,
     pub holders: Vec<TokenHolder>,
+    /// How many holders there are, and whether `holders` covers all of them
+    #[serde(default)]
+    pub holder_counts: Option<HolderCounts>,
     pub creation_timestamp: Option<i64>,
+    /// Wallet that paid for the mint's initialization; `None` when the first
+    /// transaction could not be found or did not initialize the mint
+    #[serde(default)]
+    pub creator: Option<TokenCreator>,
+    /// Authority-related instructions from the mint's transactions; `None` unless
+    /// the provider was asked to walk them
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub authority_history: Option<AuthorityHistory>,
+    /// Wallets that received the token in its first slots; `None` unless the
+    /// provider was asked to read the launch
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub launch: Option<LaunchActivity>,
+    /// Transfer out of a top holder's account run through `simulateTransaction`;
+    /// `None` unless the provider was asked to simulate, or no holder could send
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub transfer_simulation: Option<TransferSimulation>,
+    /// Launchpad bonding curve of the mint, when it was launched on one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bonding_curve: Option<BondingCurve>,
+    /// Well-known token whose name or symbol the metadata imitates
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub impersonation: Option<Impersonation>,
+    #[serde(default)]
+    pub fetch_status: FetchStatus,
 
     #[cfg(feature = "lp-analysis")]
     pub lp_pools: Vec<LiquidityPool>,
@@ -21,7 +61,484 @@ pub struct TokenMetadata {
     pub name: String,
     pub symbol: String,
     pub uri: String,
+    /// A verified collection or at least one verified creator
     pub is_verified: bool,
+    /// Key allowed to change the metadata while `is_mutable`
+    #[serde(default)]
+    pub update_authority: Option<Pubkey>,
+    #[serde(default)]
+    pub is_mutable: bool,
+    #[serde(default)]
+    pub primary_sale_happened: bool,
+    #[serde(default)]
+    pub seller_fee_basis_points: u16,
+    #[serde(default)]
+    pub creators: Vec<MetadataCreator>,
+    #[serde(default)]
+    pub collection: Option<MetadataCollection>,
+    /// Metaplex token standard, e.g. "Fungible" or "NonFungible"
+    #[serde(default)]
+    pub token_standard: Option<String>,
+    /// Extra key/value fields of Token-2022 metadata
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additional_metadata: Vec<(String, String)>,
+    #[serde(default)]
+    pub source: MetadataSource,
+}
+
+/// Where the metadata was read from
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetadataSource {
+    /// Metaplex Token Metadata account
+    #[default]
+    Metaplex,
+    /// Token-2022 `TokenMetadata` extension, found through the mint's `MetadataPointer`
+    #[serde(rename = "token_2022")]
+    Token2022,
+}
+
+impl MetadataSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MetadataSource::Metaplex => "metaplex",
+            MetadataSource::Token2022 => "token_2022",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCreator {
+    pub address: Pubkey,
+    /// The creator signed to confirm they created this token
+    pub verified: bool,
+    /// Share of royalties, in percent
+    pub share: u8,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MetadataCollection {
+    pub key: Pubkey,
+    /// The collection's authority confirmed membership
+    pub verified: bool,
+}
+
+impl TokenMetadata {
+    /// Metadata with only the display fields known
+    pub fn basic(name: String, symbol: String, uri: String) -> Self {
+        Self {
+            name,
+            symbol,
+            uri,
+            is_verified: false,
+            update_authority: None,
+            is_mutable: false,
+            primary_sale_happened: false,
+            seller_fee_basis_points: 0,
+            creators: Vec::new(),
+            collection: None,
+            token_standard: None,
+            additional_metadata: Vec::new(),
+            source: MetadataSource::Metaplex,
+        }
+    }
+
+    pub fn verified_creators(&self) -> impl Iterator<Item = &MetadataCreator> {
+        self.creators.iter().filter(|creator| creator.verified)
+    }
+
+    pub fn has_verified_collection(&self) -> bool {
+        self.collection
+            .as_ref()
+            .is_some_and(|collection| collection.verified)
+    }
+}
+
+/// Token-2022 mint extension, decoded where its settings matter for risk
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum MintExtension {
+    /// Delegate that can transfer or burn tokens from any account
+    PermanentDelegate {
+        delegate: Option<Pubkey>,
+    },
+    /// Every transfer invokes `program_id`, which can reject it
+    TransferHook {
+        authority: Option<Pubkey>,
+        program_id: Option<Pubkey>,
+    },
+    /// Fee withheld on every transfer (the newer, or upcoming, fee schedule)
+    TransferFeeConfig {
+        fee_bps: u16,
+        max_fee: u64,
+        config_authority: Option<Pubkey>,
+        withdraw_authority: Option<Pubkey>,
+    },
+    /// State new token accounts start in
+    DefaultAccountState {
+        frozen: bool,
+    },
+    /// Tokens can never leave the account they were minted to
+    NonTransferable,
+    MintCloseAuthority {
+        authority: Option<Pubkey>,
+    },
+    /// Authority can pause all transfers, mints and burns
+    Pausable {
+        authority: Option<Pubkey>,
+        paused: bool,
+    },
+    /// Balances can be hidden from everyone but the auditor
+    ConfidentialTransfer {
+        authority: Option<Pubkey>,
+        auto_approve: bool,
+    },
+    /// Any other extension, by its Token-2022 name
+    Other {
+        name: String,
+    },
+}
+
+impl MintExtension {
+    pub fn name(&self) -> &str {
+        match self {
+            MintExtension::PermanentDelegate { .. } => "PermanentDelegate",
+            MintExtension::TransferHook { .. } => "TransferHook",
+            MintExtension::TransferFeeConfig { .. } => "TransferFeeConfig",
+            MintExtension::DefaultAccountState { .. } => "DefaultAccountState",
+            MintExtension::NonTransferable => "NonTransferable",
+            MintExtension::MintCloseAuthority { .. } => "MintCloseAuthority",
+            MintExtension::Pausable { .. } => "Pausable",
+            MintExtension::ConfidentialTransfer { .. } => "ConfidentialTransferMint",
+            MintExtension::Other { name } => name,
+        }
+    }
+}
+
+/// What kind of account holds an authority
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthorityKind {
+    /// A keypair wallet: system-owned (or unfunded) and on the ed25519 curve
+    Wallet,
+    /// SPL Token `Multisig` account: `threshold` of `signers` must sign
+    Multisig { threshold: u8, signers: u8 },
+    /// Program-derived address, owned by `program` when it has data; `label` names
+    /// well-known programs such as Squads or SPL Governance
+    Pda {
+        program: Option<Pubkey>,
+        label: Option<String>,
+    },
+    /// Executable program account
+    Program,
+}
+
+impl AuthorityKind {
+    /// Noun phrase for rule descriptions, e.g. "a 2-of-3 SPL multisig"
+    pub fn describe(&self) -> String {
+        match self {
+            AuthorityKind::Wallet => "a single wallet".to_string(),
+            AuthorityKind::Multisig { threshold, signers } => {
+                format!("a {}-of-{} SPL multisig", threshold, signers)
+            }
+            AuthorityKind::Pda {
+                label: Some(label), ..
+            } => format!("a {} account", label),
+            AuthorityKind::Pda {
+                program: Some(program),
+                ..
+            } => format!("an account of program {}", program),
+            AuthorityKind::Pda { .. } => "a program-derived address".to_string(),
+            AuthorityKind::Program => "an executable program".to_string(),
+        }
+    }
+
+    /// Whether several keys control the authority: an SPL multisig, or an account
+    /// of a known governance program. A PDA of any other program is only as safe
+    /// as that program, which may well be the deployer's own.
+    pub fn is_governed(&self) -> bool {
+        matches!(
+            self,
+            AuthorityKind::Multisig { .. } | AuthorityKind::Pda { label: Some(_), .. }
+        )
+    }
+}
+
+/// How complete the provider's data is
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct FetchStatus {
+    /// The analysis deadline expired before every request completed
+    pub timed_out: bool,
+    /// RPC endpoint that served each datum ("mint", "holders", "metadata", ...), as
+    /// `scheme://host` without the path or query that may carry an API key
+    #[serde(default)]
+    pub endpoints: BTreeMap<String, String>,
+    /// Cross-check of the critical accounts across endpoints, when quorum mode is on
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quorum: Option<QuorumCheck>,
+    /// The mint's signature history was walked back to its first transaction, so
+    /// `creation_timestamp` is the real creation time rather than a lower bound
+    #[serde(default)]
+    pub creation_complete: bool,
+}
+
+/// Result of reading the mint and metadata accounts from several endpoints
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct QuorumCheck {
+    /// Endpoints asked for the accounts
+    pub requested: usize,
+    /// Endpoints that answered
+    pub responded: usize,
+    /// Context slot every answer was read at or after
+    pub min_context_slot: Option<u64>,
+    /// Fields the endpoints disagree on, e.g. "supply: 1000 at A vs 2000 at B"
+    pub disagreements: Vec<String>,
+    /// Endpoints that could not be read, with the error
+    pub unavailable: Vec<String>,
+}
+
+impl QuorumCheck {
+    /// At least two endpoints answered and all answers agree
+    pub fn agreed(&self) -> bool {
+        self.responded >= 2 && self.disagreements.is_empty()
+    }
+}
+
+/// Holder counts, aggregated by owner wallet
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize)]
+pub struct HolderCounts {
+    /// Owners with at least one token account for the mint, including empty ones
+    pub total: usize,
+    /// Owners with a non-zero balance
+    pub non_zero: usize,
+    /// `false` when the counts come from a capped view (the largest accounts only)
+    /// and are a lower bound
+    pub exact: bool,
+}
+
+/// Slots after the mint's creation read for launch buyers
+pub const LAUNCH_WINDOW_SLOTS: u64 = 20;
+
+/// Token receipts in the mint's earliest transactions
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LaunchActivity {
+    /// Slot of the mint's first transaction
+    pub creation_slot: u64,
+    /// Slots after `creation_slot` that were read
+    pub window_slots: u64,
+    /// Wallets other than the creator and program accounts, earliest first
+    pub buyers: Vec<EarlyBuyer>,
+    /// Every transaction in the window was read and every buyer's balance looked up
+    pub complete: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EarlyBuyer {
+    pub wallet: Pubkey,
+    /// Slot of the wallet's first receipt
+    pub slot: u64,
+    /// Base units received within the window
+    pub received: u64,
+    /// Current balance; `None` when it couldn't be read
+    pub balance: Option<u64>,
+    /// Received in a slot where the creator also sent a transaction
+    pub bundled: bool,
+}
+
+impl LaunchActivity {
+    /// Buyers that received tokens at most `slots` after creation
+    pub fn within(&self, slots: u64) -> impl Iterator<Item = &EarlyBuyer> {
+        let last_slot = self.creation_slot.saturating_add(slots);
+        self.buyers
+            .iter()
+            .filter(move |buyer| buyer.slot <= last_slot)
+    }
+}
+
+/// Transfer from a top holder's token account to a new wallet, simulated
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TransferSimulation {
+    /// Owner of the source account, which pays and signs
+    pub holder: Pubkey,
+    /// Base units sent
+    pub amount: u64,
+    /// Largest fee the mint's transfer fee config allows for `amount`
+    pub declared_fee: u64,
+    pub outcome: SimulationOutcome,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "result", rename_all = "snake_case")]
+pub enum SimulationOutcome {
+    /// The transfer went through; the destination was credited `received`
+    Transferred { received: u64 },
+    /// An instruction was rejected: by the token program, a transfer hook or
+    /// the state of an account
+    Failed { error: String, logs: Vec<String> },
+}
+
+impl TransferSimulation {
+    /// Base units withheld beyond the declared fee; `None` unless the transfer went through
+    pub fn excess_fee(&self) -> Option<u64> {
+        match self.outcome {
+            SimulationOutcome::Transferred { received } => Some(
+                self.amount
+                    .saturating_sub(received)
+                    .saturating_sub(self.declared_fee),
+            ),
+            SimulationOutcome::Failed { .. } => None,
+        }
+    }
+}
+
+/// Where a launchpad token is in its lifecycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LaunchStage {
+    /// Trading against the bonding curve, which holds the unsold supply and the
+    /// SOL paid for the rest
+    BondingCurve,
+    /// The curve sold out and its liquidity migrated to an AMM pool
+    Graduated,
+}
+
+impl LaunchStage {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LaunchStage::BondingCurve => "bonding_curve",
+            LaunchStage::Graduated => "graduated",
+        }
+    }
+}
+
+/// A launchpad's bonding curve for the mint. Reserves are in base units of the
+/// token and in lamports; the virtual reserves set the curve's price.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BondingCurve {
+    /// e.g. "pump.fun"
+    pub launchpad: String,
+    pub address: Pubkey,
+    pub stage: LaunchStage,
+    pub virtual_token_reserves: u64,
+    pub virtual_sol_reserves: u64,
+    /// Tokens the curve still sells
+    pub real_token_reserves: u64,
+    /// SOL paid into the curve
+    pub real_sol_reserves: u64,
+    /// Share of the curve's sellable supply already bought
+    pub progress_pct: f64,
+    /// Wallet the launchpad records as the token's creator
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<Pubkey>,
+}
+
+impl BondingCurve {
+    /// SOL per whole token at the curve's current price
+    pub fn price_sol(&self, decimals: u8) -> Option<f64> {
+        if self.virtual_token_reserves == 0 {
+            return None;
+        }
+        let sol = self.virtual_sol_reserves as f64 / 1e9;
+        let tokens = self.virtual_token_reserves as f64 / 10f64.powi(decimals as i32);
+        Some(sol / tokens)
+    }
+}
+
+/// Timeline of authority-related instructions on the mint
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AuthorityHistory {
+    /// Oldest first
+    pub events: Vec<AuthorityEvent>,
+    /// Every transaction of the mint was decoded; otherwise there may be gaps
+    pub complete: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuthorityEvent {
+    pub signature: String,
+    pub slot: u64,
+    pub timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub action: AuthorityAction,
+}
+
+/// Token program instruction that created, moved or used an authority of the mint.
+/// Identical for the Token and Token-2022 programs.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AuthorityAction {
+    InitializeMint {
+        mint_authority: Option<Pubkey>,
+        freeze_authority: Option<Pubkey>,
+    },
+    SetAuthority {
+        /// Token program `AuthorityType`, e.g. "MintTokens" or "FreezeAccount"
+        authority_type: String,
+        /// `None` revokes the authority
+        new_authority: Option<Pubkey>,
+        /// Time of the new authority's first transaction, when its full history was read
+        #[serde(default)]
+        new_authority_first_seen: Option<i64>,
+    },
+    MintTo {
+        amount: u64,
+        destination: Pubkey,
+    },
+    Freeze {
+        account: Pubkey,
+    },
+    Thaw {
+        account: Pubkey,
+    },
+}
+
+impl AuthorityAction {
+    /// One-line description for timelines
+    pub fn describe(&self) -> String {
+        let or_none = |key: &Option<Pubkey>| {
+            key.map(|key| key.to_string())
+                .unwrap_or_else(|| "none".to_string())
+        };
+
+        match self {
+            AuthorityAction::InitializeMint {
+                mint_authority,
+                freeze_authority,
+            } => format!(
+                "Mint initialized (mint authority {}, freeze authority {})",
+                or_none(mint_authority),
+                or_none(freeze_authority)
+            ),
+            AuthorityAction::SetAuthority {
+                authority_type,
+                new_authority: None,
+                ..
+            } => format!("{} authority revoked", authority_type),
+            AuthorityAction::SetAuthority {
+                authority_type,
+                new_authority: Some(new_authority),
+                ..
+            } => format!("{} authority set to {}", authority_type, new_authority),
+            AuthorityAction::MintTo {
+                amount,
+                destination,
+            } => format!("Minted {} base units to {}", amount, destination),
+            AuthorityAction::Freeze { account } => format!("Froze account {}", account),
+            AuthorityAction::Thaw { account } => format!("Thawed account {}", account),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenCreator {
+    pub address: Pubkey,
+    /// Current balance of the mint across all the creator's token accounts
+    pub balance: Option<u64>,
+    pub wallet_age_days: Option<u64>,
+    /// `false` when the wallet's history was longer than the lookup budget and
+    /// `wallet_age_days` is a lower bound
+    #[serde(default)]
+    pub wallet_age_complete: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,37 +547,305 @@ pub struct TokenHolder {
     pub amount: u64,
     pub percentage: f64,
     pub wallet_age_days: Option<u64>,
+    /// `false` when the wallet's history was longer than the lookup budget and
+    /// `wallet_age_days` is a lower bound
+    #[serde(default)]
+    pub wallet_age_complete: bool,
+    /// Set for known non-user holders (pools, burn addresses, curves, ...),
+    /// which don't count towards concentration
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<HolderLabel>,
+    /// Sender of the wallet's first incoming SOL transfer, when it was looked up
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub funder: Option<Pubkey>,
 }
 
 #[cfg(feature = "lp-analysis")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LiquidityPool {
+    /// `raydium_amm_v4`, `raydium_cpmm`, `orca_whirlpool` or `meteora_dlmm`
     pub dex: String,
+    pub kind: PoolKind,
     pub pool_address: Pubkey,
-    pub tvl_usd: f64,
+    /// The other token of the pair
+    pub quote_mint: Pubkey,
+    /// Vault balances in base units, less fees owed to the protocol
+    pub token_reserve: u64,
+    pub quote_reserve: u64,
+    pub quote_decimals: u8,
+    /// Price of one whole token in whole quote tokens
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price: Option<f64>,
+    /// Price of one whole token in USD; `None` when the quote token has no known price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub price_usd: Option<f64>,
+    /// Fungible LP token of a constant-product pool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub lp_token: Option<LpToken>,
+    /// Liquidity positions of a concentrated-liquidity pool
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub positions: Option<PositionOwnership>,
+    /// Pool value in USD; `None` when the quote token has no known price
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tvl_usd: Option<f64>,
+    /// Some LP tokens or positions sit in a known locker that hasn't unlocked yet
     pub lp_locked: bool,
-    pub lp_burned: bool,
+    /// Locks holding the pool's LP tokens or position NFTs, soonest unlock first
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub locks: Vec<LpLock>,
+    /// Wallet that opened the pool, when the DEX records it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opened_by: Option<Pubkey>,
+    /// The creator opened the pool or provides its liquidity
     pub creator_is_lp_provider: bool,
 }
 
+#[cfg(feature = "lp-analysis")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PoolKind {
+    /// Liquidity over the whole price range, owned through an LP token
+    ConstantProduct,
+    /// Liquidity in price ranges or bins, owned through individual positions
+    Concentrated,
+}
+
+#[cfg(feature = "lp-analysis")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LpToken {
+    pub mint: Pubkey,
+    /// LP tokens the pool has issued and not redeemed
+    pub issued: u64,
+    /// Current supply of the LP mint; below `issued` when LP tokens were burned
+    pub supply: u64,
+    /// Share of issued LP tokens burned without being redeemed
+    pub burned_pct: f64,
+    /// Share of the LP supply held by the token's creator, when the creator is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_pct: Option<f64>,
+}
+
+/// LP tokens or a position NFT held by a locker or vesting program
+#[cfg(feature = "lp-analysis")]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LpLock {
+    /// e.g. "Streamflow"
+    pub locker: String,
+    /// The lock (stream or escrow) account
+    pub address: Pubkey,
+    /// Wallet that can withdraw once the lock ends
+    pub beneficiary: Pubkey,
+    /// Share of the LP supply, or of the pool's liquidity, still locked
+    pub pct: f64,
+    /// Unix time the last of the locked tokens unlock
+    pub unlock_at: i64,
+    /// Share the lock covers in total, of which `pct` is still locked
+    pub total_pct: f64,
+    /// How the locked tokens are released
+    pub schedule: VestingSchedule,
+}
+
+#[cfg(feature = "lp-analysis")]
+impl LpLock {
+    /// Share still locked at unix time `time`
+    pub fn locked_pct_at(&self, time: i64) -> f64 {
+        if self.schedule.total == 0 {
+            return 0.0;
+        }
+        self.total_pct * self.schedule.locked_at(time) as f64 / self.schedule.total as f64
+    }
+}
+
+/// Release schedule of a lock in base units: nothing before `cliff_at`, then
+/// `cliff_amount`, then `amount_per_period` every `period` seconds until `total`
+/// is released. A plain lock releases everything at the cliff.
+#[cfg(feature = "lp-analysis")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VestingSchedule {
+    pub cliff_at: i64,
+    pub cliff_amount: u64,
+    pub period: i64,
+    pub amount_per_period: u64,
+    pub total: u64,
+    /// Already withdrawn by the beneficiary
+    pub withdrawn: u64,
+}
+
+#[cfg(feature = "lp-analysis")]
+impl VestingSchedule {
+    /// Released by unix time `time`, whether withdrawn or not
+    pub fn unlocked_at(&self, time: i64) -> u64 {
+        if time < self.cliff_at {
+            return 0;
+        }
+        let periods = if self.period > 0 {
+            ((time - self.cliff_at) / self.period) as u64
+        } else {
+            u64::MAX
+        };
+        self.cliff_amount
+            .saturating_add(self.amount_per_period.saturating_mul(periods))
+            .min(self.total)
+    }
+
+    /// Still locked at unix time `time`: released tokens are out of the lock even
+    /// while they sit unclaimed
+    pub fn locked_at(&self, time: i64) -> u64 {
+        self.total
+            .saturating_sub(self.unlocked_at(time).max(self.withdrawn))
+    }
+
+    /// Unix time everything is released; `i64::MAX` when the schedule never completes
+    pub fn end(&self) -> i64 {
+        let rest = self.total.saturating_sub(self.cliff_amount);
+        if rest == 0 || self.period <= 0 {
+            return self.cliff_at;
+        }
+        if self.amount_per_period == 0 {
+            return i64::MAX;
+        }
+        let periods = rest.div_ceil(self.amount_per_period);
+        i64::try_from(periods)
+            .ok()
+            .and_then(|periods| periods.checked_mul(self.period))
+            .and_then(|secs| self.cliff_at.checked_add(secs))
+            .unwrap_or(i64::MAX)
+    }
+}
+
+#[cfg(feature = "lp-analysis")]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PositionOwnership {
+    /// Open positions found for the pool
+    pub positions: usize,
+    /// Share of the pool's liquidity in the creator's positions, when the
+    /// creator is known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator_pct: Option<f64>,
+    /// Share of the pool's liquidity in positions held by known lockers
+    pub locked_pct: f64,
+}
+
 impl TokenData {
     pub fn total_supply(&self) -> u64 {
         self.supply
     }
 
+    /// Number of holders with a non-zero balance
     pub fn holder_count(&self) -> usize {
-        self.holders.len()
+        self.holder_counts
+            .map(|counts| counts.non_zero)
+            .unwrap_or(self.holders.len())
+    }
+
+    /// Whether [`Self::holder_count`] is the true count rather than a lower bound
+    pub fn holder_count_exact(&self) -> bool {
+        self.holder_counts.is_some_and(|counts| counts.exact)
     }
 
     pub fn creator_address(&self) -> Option<Pubkey> {
-        self.holders.first().map(|h| h.address)
+        self.creator.as_ref().map(|creator| creator.address)
+    }
+
+    /// The creator's current balance as a percentage of supply
+    pub fn creator_supply_percentage(&self) -> Option<f64> {
+        let balance = self.creator.as_ref()?.balance?;
+        if self.supply == 0 {
+            return Some(0.0);
+        }
+        Some(balance as f64 / self.supply as f64 * 100.0)
+    }
+
+    /// Holders that aren't labeled as pools, burn addresses or other known
+    /// programs, largest first
+    pub fn user_holders(&self) -> impl Iterator<Item = &TokenHolder> {
+        self.holders.iter().filter(|holder| {
+            holder
+                .label
+                .as_ref()
+                .map_or(true, |label| !label.kind.is_non_user())
+        })
+    }
+
+    /// User holders funded by a common wallet, largest share first
+    pub fn funding_clusters(&self) -> Vec<FundingCluster> {
+        FundingCluster::find(self.user_holders())
+    }
+
+    /// Distribution metrics over [`Self::user_holders`], each funding cluster
+    /// counted as a single holder
+    pub fn distribution(&self) -> Option<HolderDistribution> {
+        let clusters = self.funding_clusters();
+        let clustered = |holder: &TokenHolder| {
+            clusters
+                .iter()
+                .any(|cluster| cluster.members.contains(&holder.address))
+        };
+
+        let mut entities: Vec<TokenHolder> = clusters
+            .iter()
+            .map(|cluster| TokenHolder {
+                address: cluster.funder,
+                amount: cluster.amount,
+                percentage: cluster.percentage,
+                wallet_age_days: None,
+                wallet_age_complete: false,
+                label: None,
+                funder: None,
+            })
+            .chain(
+                self.user_holders()
+                    .filter(|holder| !clustered(holder))
+                    .cloned(),
+            )
+            .collect();
+        entities.sort_by_key(|holder| std::cmp::Reverse(holder.amount));
+
+        HolderDistribution::from_holders(&entities)
+    }
+
+    /// Price drop, in percent, from selling `sell_pct` of supply into all priced
+    /// pools at once; `None` without a priced pool.
+    ///
+    /// Each pool counts as a constant-product pool holding its quote reserve and
+    /// the same value in tokens. That is exact for constant-product pools and
+    /// leaves out the extra depth concentrated liquidity has near the price; fees
+    /// are ignored.
+    #[cfg(feature = "lp-analysis")]
+    pub fn sell_price_impact(&self, sell_pct: f64) -> Option<f64> {
+        // Token side of each pool in whole tokens
+        let depths: Vec<f64> = self
+            .lp_pools
+            .iter()
+            .filter_map(|pool| {
+                let quote = pool.quote_reserve as f64 / 10f64.powi(pool.quote_decimals as i32);
+                Some(quote / pool.price?)
+            })
+            .collect();
+        if depths.is_empty() {
+            return None;
+        }
+
+        let depth: f64 = depths.iter().sum();
+        let sold = self.supply as f64 / 10f64.powi(self.decimals as i32) * sell_pct / 100.0;
+        if depth + sold <= 0.0 {
+            return None;
+        }
+        // Selling dx into x * y = k gets y / (x + dx) per token, against y / x before
+        Some(sold / (depth + sold) * 100.0)
+    }
+
+    /// Lifecycle stage of a launchpad token; `None` when it has no known curve
+    pub fn launch_stage(&self) -> Option<LaunchStage> {
+        self.bonding_curve.as_ref().map(|curve| curve.stage)
     }
 
-    pub fn creator_supply_percentage(&self) -> f64 {
-        self.holders.first().map(|h| h.percentage).unwrap_or(0.0)
+    pub fn top_holder_percentage(&self) -> Option<f64> {
+        self.user_holders().next().map(|h| h.percentage)
     }
 
     pub fn is_supply_concentrated(&self, threshold: f64) -> bool {
-        self.creator_supply_percentage() > threshold
+        self.creator_supply_percentage()
+            .is_some_and(|percentage| percentage > threshold)
     }
 }
@@ -6,6 +6,11 @@ use solana_sdk::pubkey::Pubkey;
 
 #[async_trait]
 pub trait TokenDataProvider: Send + Sync {
+    /// Provider for one analysis, whose calls all count against its time budget
+    fn begin_analysis(&self) -> Self
+    where
+        Self: Sized;
+
     async fn fetch_token_data(&self, mint: &Pubkey) -> Result<TokenData>;
 
     async fn enrich_holder_ages(&self, holders: &mut [TokenHolder]) -> Result<()>;
@@ -1,4 +1,5 @@
 use crate::config::Config;
+use crate::error::RiskError;
 use crate::heuristics::RiskRule;
 use crate::model::RiskReport;
 use crate::provider::TokenDataProvider;
@@ -21,42 +22,127 @@ impl<P: TokenDataProvider> RiskAnalyzer<P> {
     }
 
     pub async fn analyze(&self, mint: &Pubkey) -> Result<RiskReport> {
+        // Every call below, wallet ages included, shares the analysis deadline
+        let provider = self.provider.begin_analysis();
+
         // Fetch token data (cached if available)
-        let mut token_data = self.provider.fetch_token_data(mint).await?;
+        let mut token_data = provider.fetch_token_data(mint).await?;
 
         // Create report
-        let mut report = RiskReport::new(*mint, self.config_profile());
+        let mut report = RiskReport::new(*mint, self.config.identity());
 
         report.metrics.total_supply = Some(token_data.supply);
         report.metrics.decimals = Some(token_data.decimals);
+        report.metrics.extensions = token_data
+            .extensions
+            .iter()
+            .map(|ext| ext.name().to_string())
+            .collect();
 
         // Top holder % — самый большой процент (holders должны быть отсортированы по убыванию)
-        if let Some(top_holder) = token_data.holders.first() {
-            report.metrics.top_holder_pct = Some(top_holder.percentage);
+        if let Some(pct) = token_data.top_holder_percentage() {
+            report.metrics.top_holder_pct = Some(pct);
         } else {
             // опционально: если holders пустой — можно залогировать или оставить None
             eprintln!("DEBUG: No holders found for mint {}", mint);
         }
 
         // Track data source status
-        if token_data.holders.is_empty() {
-            report.data_sources.holders = "partial".to_string();
+        if token_data.fetch_status.timed_out {
+            report.data_sources.rpc = "timeout".to_string();
         }
 
-        if token_data.metadata.is_none() {
-            report.data_sources.metadata = "missing".to_string();
+        if !token_data.fetch_status.endpoints.is_empty() {
+            report.data_sources.endpoints = Some(token_data.fetch_status.endpoints.clone());
         }
 
-        // Enrich top holder wallet ages (only top 10 to limit RPC calls)
-        if self
-            .provider
-            .enrich_holder_ages(&mut token_data.holders)
-            .await
-            .is_err()
+        if let Some(quorum) = &token_data.fetch_status.quorum {
+            let status = if !quorum.disagreements.is_empty() {
+                "disagree"
+            } else if quorum.agreed() && quorum.responded == quorum.requested {
+                "ok"
+            } else {
+                "partial"
+            };
+            report.data_sources.quorum = Some(status.to_string());
+
+            for disagreement in &quorum.disagreements {
+                report
+                    .warnings
+                    .push(format!("RPC endpoints disagree on {}", disagreement));
+            }
+            if quorum.responded < quorum.requested {
+                report.warnings.push(format!(
+                    "Quorum incomplete: {} of {} endpoints answered",
+                    quorum.responded, quorum.requested
+                ));
+            }
+        }
+
+        report.metrics.holders_exact = token_data.holder_count_exact();
+        report.data_sources.holders = if token_data.holders.is_empty() {
+            if token_data.fetch_status.timed_out {
+                "timeout".to_string()
+            } else {
+                "partial".to_string()
+            }
+        } else if token_data.holder_count_exact() {
+            "exact".to_string()
+        } else {
+            "truncated".to_string()
+        };
+
+        report.metrics.creator = token_data.creator_address();
+        match &token_data.creator {
+            Some(creator) if creator.wallet_age_days.is_some() => {
+                if !creator.wallet_age_complete {
+                    report.data_sources.wallet_age = "truncated".to_string();
+                }
+            }
+            _ => report.data_sources.wallet_age = "missing".to_string(),
+        }
+
+        if let Some(history) = &token_data.authority_history {
+            report.authority_history = history.events.clone();
+            report.data_sources.authority_history =
+                Some(if history.complete { "ok" } else { "partial" }.to_string());
+        }
+
+        report.transfer_simulation = token_data.transfer_simulation.clone();
+        report.bonding_curve = token_data.bonding_curve.clone();
+        report.impersonation = token_data.impersonation.clone();
+        #[cfg(feature = "lp-analysis")]
         {
+            report.lp_pools = token_data.lp_pools.clone();
+        }
+
+        report.data_sources.creation_time = if token_data.creation_timestamp.is_none() {
+            "missing".to_string()
+        } else if token_data.fetch_status.creation_complete {
+            "ok".to_string()
+        } else {
+            "truncated".to_string()
+        };
+
+        match &token_data.metadata {
+            Some(metadata) => {
+                report.data_sources.metadata_source = Some(metadata.source.as_str().to_string());
+            }
+            None => report.data_sources.metadata = "missing".to_string(),
+        }
+
+        // Enrich top holder wallet ages (only top 10 to limit RPC calls)
+        if let Err(e) = provider.enrich_holder_ages(&mut token_data.holders).await {
+            if RiskError::is_timeout_err(&e) {
+                report.data_sources.rpc = "timeout".to_string();
+            }
             report.data_sources.wallet_age = "missing".to_string();
         }
 
+        // Funders come with the wallet ages, so clusters are only known now
+        report.funding_clusters = token_data.funding_clusters();
+        report.metrics.distribution = token_data.distribution();
+
         // Apply rules
         for rule in &self.rules {
             rule.evaluate(&token_data, &self.config, &mut report);
@@ -73,15 +159,4 @@ impl<P: TokenDataProvider> RiskAnalyzer<P> {
 
         Ok(report)
     }
-
-    fn config_profile(&self) -> String {
-        // Determine profile based on thresholds
-        if self.config.thresholds.creator_supply_high_pct <= 40.0 {
-            "conservative".to_string()
-        } else if self.config.thresholds.creator_supply_high_pct >= 70.0 {
-            "degenerate".to_string()
-        } else {
-            "balanced".to_string()
-        }
-    }
 }
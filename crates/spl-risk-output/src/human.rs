@@ -1,6 +1,7 @@
 use anyhow::Result;
 use colored::*;
 use spl_risk_core::model::report::RiskReport;
+use spl_risk_core::model::token::SimulationOutcome;
 
 pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
     println!();
@@ -18,6 +19,13 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
     // Token & Profile
     println!("{}: {}", "TOKEN".bright_cyan().bold(), report.mint);
     println!("{}: {}", "PROFILE".bright_cyan().bold(), report.profile);
+    if verbose {
+        println!(
+            "{}: {}",
+            "PROFILE HASH".bright_cyan().bold(),
+            report.profile.hash.bright_black()
+        );
+    }
     println!();
 
     // RISK SCORE с цветом
@@ -106,11 +114,33 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
         if let Some(d) = report.metrics.decimals {
             println!("  {:<27}: {}", "Decimals", d);
         }
-        println!(
-            "  {:<27}: {:.2}%",
-            "Creator Supply", report.metrics.creator_supply_pct
-        );
-        println!("  {:<27}: {}", "Holders", report.metrics.holders);
+        if !report.metrics.extensions.is_empty() {
+            println!(
+                "  {:<27}: {}",
+                "Token-2022 Extensions",
+                report.metrics.extensions.join(", ")
+            );
+        }
+        match report.metrics.creator {
+            Some(creator) => {
+                println!("  {:<27}: {}", "Creator", creator);
+                println!(
+                    "  {:<27}: {:.2}%",
+                    "Creator Supply", report.metrics.creator_supply_pct
+                );
+            }
+            None => println!("  {:<27}: {}", "Creator", "unknown".bright_black()),
+        }
+        if report.metrics.holders_exact {
+            println!("  {:<27}: {}", "Holders", report.metrics.holders);
+        } else {
+            println!(
+                "  {:<27}: {}+ {}",
+                "Holders",
+                report.metrics.holders,
+                "(largest accounts only)".bright_black()
+            );
+        }
 
         if let Some(pct) = report.metrics.top_holder_pct {
             let warning = if pct > 30.0 {
@@ -120,6 +150,46 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
             };
             println!("  {:<27}: {:.2}%{}", "Top Holder", pct, warning);
         }
+        if let Some(ref distribution) = report.metrics.distribution {
+            println!(
+                "  {:<27}: {:.2}% / {:.2}% / {:.2}%",
+                "Top 5 / 10 / 20",
+                distribution.top5_pct,
+                distribution.top10_pct,
+                distribution.top20_pct
+            );
+            println!("  {:<27}: {:.0}", "HHI", distribution.hhi);
+            println!("  {:<27}: {:.2}", "Gini", distribution.gini);
+            match distribution.nakamoto {
+                Some(count) => println!("  {:<27}: {}", "Nakamoto Coefficient", count),
+                None => println!(
+                    "  {:<27}: {}",
+                    "Nakamoto Coefficient",
+                    "n/a (holders seen own under 50%)".bright_black()
+                ),
+            }
+        }
+        if let Some(ref launch) = report.metrics.launch {
+            let partial = if launch.complete {
+                ""
+            } else {
+                " (partial launch data)"
+            };
+            let unknown = if launch.unknown_balances > 0 {
+                format!(" + {} unknown", launch.unknown_balances)
+            } else {
+                String::new()
+            };
+            println!(
+                "  {:<27}: {} ({} bundled), hold {:.2}%{}{}",
+                "Launch Snipers",
+                launch.sniper_wallets,
+                launch.bundled_wallets,
+                launch.sniper_held_pct,
+                unknown.bright_black(),
+                partial.bright_black()
+            );
+        }
         if let Some(age) = report.metrics.wallet_age_days {
             let years = age as f64 / 365.25;
             println!("  {:<27}: {} days ≈ {:.1} years", "Wallet Age", age, years);
@@ -127,6 +197,194 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
 
         println!();
 
+        // FUNDING CLUSTERS
+        if !report.funding_clusters.is_empty() {
+            println!("{}", "FUNDING CLUSTERS:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            for cluster in &report.funding_clusters {
+                println!(
+                    "  Funded by {} : {:.2}% of supply",
+                    cluster.funder, cluster.percentage
+                );
+                for member in &cluster.members {
+                    println!("    {}", member.to_string().bright_black());
+                }
+            }
+            println!();
+        }
+
+        // AUTHORITY HISTORY
+        if !report.authority_history.is_empty() {
+            println!("{}", "AUTHORITY HISTORY:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            for event in &report.authority_history {
+                let when = event
+                    .timestamp
+                    .and_then(|ts| chrono::DateTime::from_timestamp(ts, 0))
+                    .map(|time| time.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_else(|| format!("slot {}", event.slot));
+                println!("  {:<16}  {}", when.bright_black(), event.action.describe());
+            }
+            println!();
+        }
+
+        // IMPERSONATION
+        if let Some(ref impersonation) = report.impersonation {
+            println!("{}", "IMPERSONATION:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            println!(
+                "  {:<27}: {} ({})",
+                "Imitates", impersonation.symbol, impersonation.name
+            );
+            println!(
+                "  {:<27}: {}",
+                "Real Mint",
+                impersonation.mint.to_string().bright_black()
+            );
+            println!(
+                "  {:<27}: {} \"{}\" ({})",
+                "Match",
+                impersonation.field,
+                impersonation.value,
+                impersonation.kind.as_str()
+            );
+            println!();
+        }
+
+        // BONDING CURVE
+        if let Some(ref curve) = report.bonding_curve {
+            println!("{}", "BONDING CURVE:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            println!(
+                "  {:<27}: {} {}",
+                "Launchpad",
+                curve.launchpad,
+                curve.address.to_string().bright_black()
+            );
+            println!("  {:<27}: {}", "Stage", curve.stage.as_str());
+            println!("  {:<27}: {:.1}%", "Progress", curve.progress_pct);
+            println!(
+                "  {:<27}: {}",
+                "Tokens Left on Curve",
+                format_number(curve.real_token_reserves)
+            );
+            println!(
+                "  {:<27}: {:.2} SOL",
+                "SOL in Curve",
+                curve.real_sol_reserves as f64 / 1e9
+            );
+            println!();
+        }
+
+        // LIQUIDITY POOLS
+        #[cfg(feature = "lp-analysis")]
+        if report.flags.lp_detected {
+            println!("{}", "LIQUIDITY POOLS:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            if let Some(tvl) = report.metrics.total_lp_tvl {
+                println!("  {:<27}: ${:.0}", "Total Value", tvl);
+            }
+            if let Some(price) = report.metrics.price_usd {
+                println!("  {:<27}: ${:.6e}", "Token Price", price);
+            }
+            if let Some(impact) = report.metrics.sell_impact_pct {
+                println!("  {:<27}: {:.2}%", "Sell Impact", impact);
+            }
+            for pool in &report.lp_pools {
+                println!(
+                    "  {} {}",
+                    pool.dex,
+                    pool.pool_address.to_string().bright_black()
+                );
+                println!(
+                    "    {:<25}: {} / {} {}",
+                    "Reserves",
+                    format_number(pool.token_reserve),
+                    format_number(pool.quote_reserve),
+                    pool.quote_mint.to_string().bright_black()
+                );
+                if let Some(price) = pool.price {
+                    println!("    {:<25}: {:.6e}", "Price", price);
+                }
+                if let Some(tvl) = pool.tvl_usd {
+                    println!("    {:<25}: ${:.0}", "Value", tvl);
+                }
+                if let Some(ref lp_token) = pool.lp_token {
+                    println!("    {:<25}: {:.2}%", "LP Burned", lp_token.burned_pct);
+                    if let Some(pct) = lp_token.creator_pct {
+                        println!("    {:<25}: {:.2}%", "LP Held by Creator", pct);
+                    }
+                }
+                for lock in &pool.locks {
+                    let until = chrono::DateTime::from_timestamp(lock.unlock_at, 0)
+                        .map(|time| time.format("%Y-%m-%d").to_string())
+                        .unwrap_or_else(|| lock.unlock_at.to_string());
+                    println!(
+                        "    {:<25}: {:.2}% until {} ({} for {})",
+                        "Locked",
+                        lock.pct,
+                        until,
+                        lock.locker,
+                        lock.beneficiary.to_string().bright_black()
+                    );
+                }
+                if let Some(ref positions) = pool.positions {
+                    println!("    {:<25}: {}", "Positions", positions.positions);
+                    println!(
+                        "    {:<25}: {:.2}%",
+                        "Liquidity Locked", positions.locked_pct
+                    );
+                    if let Some(pct) = positions.creator_pct {
+                        println!("    {:<25}: {:.2}%", "Liquidity of Creator", pct);
+                    }
+                }
+            }
+            println!();
+        }
+
+        // TRANSFER SIMULATION
+        if let Some(ref simulation) = report.transfer_simulation {
+            println!("{}", "TRANSFER SIMULATION:".bright_cyan().bold());
+            println!(
+                "{}",
+                "───────────────────────────────────────────────────────────".bright_black()
+            );
+            println!("  {:<27}: {}", "From Holder", simulation.holder);
+            println!("  {:<27}: {}", "Amount", format_number(simulation.amount));
+            match &simulation.outcome {
+                SimulationOutcome::Transferred { received } => {
+                    println!("  {:<27}: {}", "Received", format_number(*received));
+                    println!(
+                        "  {:<27}: {}",
+                        "Declared Fee",
+                        format_number(simulation.declared_fee)
+                    );
+                }
+                SimulationOutcome::Failed { error, logs } => {
+                    println!("  {:<27}: {}", "Result", error.red());
+                    for line in logs {
+                        println!("    {}", line.bright_black());
+                    }
+                }
+            }
+            println!();
+        }
+
         // DATA SOURCES
         println!("{}", "DATA SOURCES:".bright_cyan().bold());
         println!(
@@ -137,10 +395,17 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
             "  RPC          : {}",
             format_data_source(&report.data_sources.rpc)
         );
-        println!(
-            "  Metadata     : {}",
-            format_data_source(&report.data_sources.metadata)
-        );
+        match report.data_sources.metadata_source {
+            Some(ref source) => println!(
+                "  Metadata     : {} ({})",
+                format_data_source(&report.data_sources.metadata),
+                source.bright_black()
+            ),
+            None => println!(
+                "  Metadata     : {}",
+                format_data_source(&report.data_sources.metadata)
+            ),
+        }
         println!(
             "  Holders      : {}",
             format_data_source(&report.data_sources.holders)
@@ -149,6 +414,26 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
             "  Wallet Age   : {}",
             format_data_source(&report.data_sources.wallet_age)
         );
+        println!(
+            "  Creation     : {}",
+            format_data_source(&report.data_sources.creation_time)
+        );
+        if let Some(ref history) = report.data_sources.authority_history {
+            println!("  Authorities  : {}", format_data_source(history));
+        }
+        if let Some(ref quorum) = report.data_sources.quorum {
+            println!("  Quorum       : {}", format_data_source(quorum));
+        }
+
+        if let Some(ref endpoints) = report.data_sources.endpoints {
+            if !endpoints.is_empty() {
+                println!();
+                println!("  Served by:");
+                for (datum, url) in endpoints {
+                    println!("    {:<14} → {}", datum, url.bright_black());
+                }
+            }
+        }
 
         if let Some(ref cached_at) = report.data_sources.cached_at {
             if !cached_at.is_empty() {
@@ -201,6 +486,7 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
 
     // Дополняем summary, если holders мало (как в твоём примере)
     if report.risk_score <= 20
+        && report.metrics.holders_exact
         && report.metrics.holders < 50
         && !summary_text.contains("low holder count")
     {
@@ -232,11 +518,14 @@ pub fn print_report(report: &RiskReport, verbose: bool) -> Result<()> {
 fn format_data_source(status: &str) -> colored::ColoredString {
     match status.to_lowercase().as_str() {
         "ok" => "✓ OK".green(),
+        "exact" => "✓ Exact".green(),
+        "truncated" => "⚠ Truncated".yellow(),
         "cached" => "⚡ Cached".bright_blue(),
         "partial" => "⚠ Partial".yellow(),
         "timeout" => "⏱ Timeout".red(),
         "missing" => "✗ Missing".red(),
         "error" => "✗ Error".bright_red(),
+        "disagree" => "✗ Disagree".bright_red(),
         _ => status.normal(),
     }
 }
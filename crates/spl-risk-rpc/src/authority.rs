@@ -0,0 +1,161 @@
+use crate::solana::{LEGACY_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID};
+use solana_program::program_pack::Pack;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::AuthorityKind;
+use spl_token_2022::state::Multisig;
+
+/// Programs whose accounts commonly hold mint or freeze authorities
+const KNOWN_PROGRAMS: &[(Pubkey, &str)] = &[
+    (
+        solana_sdk::pubkey!("SMPLecH534NA9acpos4G6x7uf3LWbCAwZQE9e8ZekMu"),
+        "Squads v3 multisig",
+    ),
+    (
+        solana_sdk::pubkey!("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf"),
+        "Squads v4 multisig",
+    ),
+    (
+        solana_sdk::pubkey!("GovER5Lthms3bLBqWub97yVrMmEogzX7xNjdXpPPCVZw"),
+        "SPL Governance",
+    ),
+];
+
+/// Classify the account holding an authority. `account` is `None` when it doesn't exist.
+///
+/// Only accounts of `KNOWN_PROGRAMS` get a label, and only labeled PDAs count as
+/// governed. A Squads vault is a system-owned PDA without data, so its program
+/// can't be told from the account alone; it classifies as a PDA of an unknown
+/// program.
+pub fn classify(address: &Pubkey, account: Option<&Account>) -> AuthorityKind {
+    let account = match account {
+        Some(account) if account.owner != SYSTEM_PROGRAM_ID => account,
+        _ if address.is_on_curve() => return AuthorityKind::Wallet,
+        _ => {
+            return AuthorityKind::Pda {
+                program: None,
+                label: None,
+            }
+        }
+    };
+
+    if account.executable {
+        return AuthorityKind::Program;
+    }
+
+    let token_owned =
+        account.owner == LEGACY_TOKEN_PROGRAM_ID || account.owner == spl_token_2022::id();
+    if token_owned && account.data.len() == Multisig::LEN {
+        if let Ok(multisig) = Multisig::unpack(&account.data) {
+            return AuthorityKind::Multisig {
+                threshold: multisig.m,
+                signers: multisig.n,
+            };
+        }
+    }
+
+    AuthorityKind::Pda {
+        program: Some(account.owner),
+        label: KNOWN_PROGRAMS
+            .iter()
+            .find(|(program, _)| *program == account.owner)
+            .map(|(_, label)| label.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn account(owner: Pubkey, data: Vec<u8>, executable: bool) -> Account {
+        Account {
+            lamports: 1_000_000,
+            data,
+            owner,
+            executable,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_classifies_wallets_and_pdas() {
+        let wallet = solana_sdk::signature::Keypair::new();
+        let wallet = solana_sdk::signer::Signer::pubkey(&wallet);
+        let system = SYSTEM_PROGRAM_ID;
+
+        assert_eq!(classify(&wallet, None), AuthorityKind::Wallet);
+        assert_eq!(
+            classify(&wallet, Some(&account(system, Vec::new(), false))),
+            AuthorityKind::Wallet
+        );
+
+        let (vault, _) = Pubkey::find_program_address(&[b"vault"], &Pubkey::new_unique());
+        assert_eq!(
+            classify(&vault, None),
+            AuthorityKind::Pda {
+                program: None,
+                label: None,
+            }
+        );
+
+        let (squads, _) = KNOWN_PROGRAMS[1];
+        assert_eq!(
+            classify(&vault, Some(&account(squads, vec![0; 64], false))),
+            AuthorityKind::Pda {
+                program: Some(squads),
+                label: Some("Squads v4 multisig".to_string()),
+            }
+        );
+
+        assert!(classify(&vault, Some(&account(squads, vec![0; 64], false))).is_governed());
+
+        // A PDA of the deployer's own program is no safer than their wallet
+        let own_program = Pubkey::new_unique();
+        let kind = classify(&vault, Some(&account(own_program, vec![0; 64], false)));
+        assert_eq!(
+            kind,
+            AuthorityKind::Pda {
+                program: Some(own_program),
+                label: None,
+            }
+        );
+        assert!(!kind.is_governed());
+        assert!(!classify(&vault, None).is_governed());
+
+        let loader = Pubkey::new_unique();
+        assert_eq!(
+            classify(&vault, Some(&account(loader, vec![0; 36], true))),
+            AuthorityKind::Program
+        );
+    }
+
+    #[test]
+    fn test_classifies_spl_multisig() {
+        let multisig = Multisig {
+            m: 2,
+            n: 3,
+            is_initialized: true,
+            signers: [Pubkey::new_unique(); spl_token_2022::instruction::MAX_SIGNERS],
+        };
+        let mut data = vec![0; Multisig::LEN];
+        Multisig::pack(multisig, &mut data).unwrap();
+
+        for program in [LEGACY_TOKEN_PROGRAM_ID, spl_token_2022::id()] {
+            assert_eq!(
+                classify(
+                    &Pubkey::new_unique(),
+                    Some(&account(program, data.clone(), false))
+                ),
+                AuthorityKind::Multisig {
+                    threshold: 2,
+                    signers: 3,
+                }
+            );
+            assert!(classify(
+                &Pubkey::new_unique(),
+                Some(&account(program, data.clone(), false))
+            )
+            .is_governed());
+        }
+    }
+}
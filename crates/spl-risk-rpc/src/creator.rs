@@ -0,0 +1,81 @@
+use crate::history::decode_actions;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::UiTransactionStatusMeta;
+use spl_risk_core::model::token::AuthorityAction;
+
+/// The fee payer of `transaction`, if it initializes `mint`.
+///
+/// Launchpads create mints through a CPI, so inner instructions count too.
+pub fn find_creator(
+    transaction: &VersionedTransaction,
+    meta: Option<&UiTransactionStatusMeta>,
+    mint: &Pubkey,
+) -> Option<Pubkey> {
+    let initializes = decode_actions(transaction, meta, mint)
+        .iter()
+        .any(|action| matches!(action, AuthorityAction::InitializeMint { .. }));
+
+    if initializes {
+        transaction.message.static_account_keys().first().copied()
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solana::LEGACY_TOKEN_PROGRAM_ID;
+    use solana_sdk::instruction::{AccountMeta, Instruction};
+    use solana_sdk::message::{Message, VersionedMessage};
+
+    /// Token instruction tag of `InitializeMint2`
+    const INITIALIZE_MINT_2: u8 = 20;
+
+    fn transaction(payer: Pubkey, instructions: &[Instruction]) -> VersionedTransaction {
+        let message = Message::new(instructions, Some(&payer));
+        VersionedTransaction {
+            signatures: vec![Default::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::Legacy(message),
+        }
+    }
+
+    fn initialize_mint(program: Pubkey, mint: Pubkey) -> Instruction {
+        // decimals, mint authority, no freeze authority
+        let mut data = vec![INITIALIZE_MINT_2, 6];
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.push(0);
+
+        Instruction::new_with_bytes(program, &data, vec![AccountMeta::new(mint, false)])
+    }
+
+    #[test]
+    fn test_fee_payer_of_initialize_mint_is_creator() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        for program in [LEGACY_TOKEN_PROGRAM_ID, spl_token_2022::id()] {
+            let tx = transaction(payer, &[initialize_mint(program, mint)]);
+            assert_eq!(find_creator(&tx, None, &mint), Some(payer));
+        }
+    }
+
+    #[test]
+    fn test_transaction_without_initialize_mint_has_no_creator() {
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        let other_mint = transaction(
+            payer,
+            &[initialize_mint(
+                LEGACY_TOKEN_PROGRAM_ID,
+                Pubkey::new_unique(),
+            )],
+        );
+        assert_eq!(find_creator(&other_mint, None, &mint), None);
+
+        let not_token_program = transaction(payer, &[initialize_mint(Pubkey::new_unique(), mint)]);
+        assert_eq!(find_creator(&not_token_program, None, &mint), None);
+    }
+}
@@ -0,0 +1,103 @@
+use crate::layout::{pubkey_at, u64_at};
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::{BondingCurve, LaunchStage};
+
+pub const PUMP_FUN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+
+/// Anchor discriminator of pump.fun's `BondingCurve` account
+const BONDING_CURVE_DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+/// Tokens a pump.fun curve sells before it completes (793.1M at 6 decimals)
+const PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES: u64 = 793_100_000_000_000;
+
+/// The pump.fun bonding curve PDA of `mint`; it owns the curve's token account
+pub fn pump_fun_curve_address(mint: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"bonding-curve", mint.as_ref()], &PUMP_FUN_PROGRAM_ID).0
+}
+
+/// Decode a pump.fun `BondingCurve` account: virtual and real reserves, total
+/// supply, `complete` and, on accounts created since the field was added, the creator
+pub fn decode_pump_fun(address: Pubkey, data: &[u8]) -> Option<BondingCurve> {
+    if data.get(..8)? != BONDING_CURVE_DISCRIMINATOR {
+        return None;
+    }
+    let real_token_reserves = u64_at(data, 24)?;
+    let complete = *data.get(48)? != 0;
+    let sold = PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES.saturating_sub(real_token_reserves);
+
+    Some(BondingCurve {
+        launchpad: "pump.fun".to_string(),
+        address,
+        stage: if complete {
+            LaunchStage::Graduated
+        } else {
+            LaunchStage::BondingCurve
+        },
+        virtual_token_reserves: u64_at(data, 8)?,
+        virtual_sol_reserves: u64_at(data, 16)?,
+        real_token_reserves,
+        real_sol_reserves: u64_at(data, 32)?,
+        progress_pct: if complete {
+            100.0
+        } else {
+            sold as f64 / PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES as f64 * 100.0
+        },
+        creator: pubkey_at(data, 49).filter(|creator| *creator != Pubkey::default()),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn curve_data(real_token_reserves: u64, complete: bool, creator: Option<Pubkey>) -> Vec<u8> {
+        let mut data = BONDING_CURVE_DISCRIMINATOR.to_vec();
+        for value in [
+            1_073_000_000_000_000u64,
+            30_000_000_000,
+            real_token_reserves,
+            12_000_000_000,
+            1_000_000_000_000_000,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(u8::from(complete));
+        if let Some(creator) = creator {
+            data.extend_from_slice(creator.as_ref());
+        }
+        data
+    }
+
+    #[test]
+    fn test_decodes_pump_fun_curve() {
+        let address = pump_fun_curve_address(&Pubkey::new_unique());
+        let creator = Pubkey::new_unique();
+
+        let curve = decode_pump_fun(
+            address,
+            &curve_data(
+                PUMP_FUN_INITIAL_REAL_TOKEN_RESERVES / 4,
+                false,
+                Some(creator),
+            ),
+        )
+        .unwrap();
+        assert_eq!(curve.stage, LaunchStage::BondingCurve);
+        assert_eq!(curve.real_sol_reserves, 12_000_000_000);
+        assert!((curve.progress_pct - 75.0).abs() < 1e-9);
+        assert_eq!(curve.creator, Some(creator));
+        // 30 SOL against 1.073B tokens
+        assert!((curve.price_sol(6).unwrap() - 30.0 / 1_073_000_000.0).abs() < 1e-15);
+
+        // Older accounts end before the creator
+        let curve = decode_pump_fun(address, &curve_data(0, true, None)).unwrap();
+        assert_eq!(curve.stage, LaunchStage::Graduated);
+        assert_eq!(curve.progress_pct, 100.0);
+        assert_eq!(curve.creator, None);
+
+        let mut other = curve_data(0, true, None);
+        other[0] ^= 1;
+        assert_eq!(decode_pump_fun(address, &other), None);
+    }
+}
@@ -0,0 +1,151 @@
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::MintExtension;
+use spl_token_2022::extension::confidential_transfer::ConfidentialTransferMint;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
+use spl_token_2022::extension::mint_close_authority::MintCloseAuthority;
+use spl_token_2022::extension::pausable::PausableConfig;
+use spl_token_2022::extension::permanent_delegate::PermanentDelegate;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::transfer_hook::TransferHook;
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
+use spl_token_2022::state::{AccountState, Mint};
+
+/// Every extension on the mint, in TLV order. Extensions this tool doesn't score
+/// are kept by name only; a legacy SPL mint has none.
+pub fn decode(mint: &StateWithExtensions<Mint>) -> Vec<MintExtension> {
+    let Ok(types) = mint.get_extension_types() else {
+        return Vec::new();
+    };
+
+    types
+        .into_iter()
+        .map(|extension_type| {
+            decode_one(mint, extension_type).unwrap_or_else(|| MintExtension::Other {
+                name: format!("{:?}", extension_type),
+            })
+        })
+        .collect()
+}
+
+fn decode_one(
+    mint: &StateWithExtensions<Mint>,
+    extension_type: ExtensionType,
+) -> Option<MintExtension> {
+    let extension = match extension_type {
+        ExtensionType::PermanentDelegate => {
+            let ext = mint.get_extension::<PermanentDelegate>().ok()?;
+            MintExtension::PermanentDelegate {
+                delegate: Option::<Pubkey>::from(ext.delegate),
+            }
+        }
+        ExtensionType::TransferHook => {
+            let ext = mint.get_extension::<TransferHook>().ok()?;
+            MintExtension::TransferHook {
+                authority: Option::<Pubkey>::from(ext.authority),
+                program_id: Option::<Pubkey>::from(ext.program_id),
+            }
+        }
+        ExtensionType::TransferFeeConfig => {
+            let ext = mint.get_extension::<TransferFeeConfig>().ok()?;
+            MintExtension::TransferFeeConfig {
+                fee_bps: u16::from(ext.newer_transfer_fee.transfer_fee_basis_points),
+                max_fee: u64::from(ext.newer_transfer_fee.maximum_fee),
+                config_authority: Option::<Pubkey>::from(ext.transfer_fee_config_authority),
+                withdraw_authority: Option::<Pubkey>::from(ext.withdraw_withheld_authority),
+            }
+        }
+        ExtensionType::DefaultAccountState => {
+            let ext = mint.get_extension::<DefaultAccountState>().ok()?;
+            MintExtension::DefaultAccountState {
+                frozen: ext.state == AccountState::Frozen as u8,
+            }
+        }
+        ExtensionType::NonTransferable => MintExtension::NonTransferable,
+        ExtensionType::MintCloseAuthority => {
+            let ext = mint.get_extension::<MintCloseAuthority>().ok()?;
+            MintExtension::MintCloseAuthority {
+                authority: Option::<Pubkey>::from(ext.close_authority),
+            }
+        }
+        ExtensionType::Pausable => {
+            let ext = mint.get_extension::<PausableConfig>().ok()?;
+            MintExtension::Pausable {
+                authority: Option::<Pubkey>::from(ext.authority),
+                paused: bool::from(ext.paused),
+            }
+        }
+        ExtensionType::ConfidentialTransferMint => {
+            let ext = mint.get_extension::<ConfidentialTransferMint>().ok()?;
+            MintExtension::ConfidentialTransfer {
+                authority: Option::<Pubkey>::from(ext.authority),
+                auto_approve: bool::from(ext.auto_approve_new_accounts),
+            }
+        }
+        _ => return None,
+    };
+
+    Some(extension)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::program_pack::Pack;
+    use spl_token_2022::extension::{BaseStateWithExtensionsMut, StateWithExtensionsMut};
+
+    #[test]
+    fn test_decodes_risky_extensions() {
+        let delegate = Pubkey::new_unique();
+        let hook_program = Pubkey::new_unique();
+
+        let types = [
+            ExtensionType::PermanentDelegate,
+            ExtensionType::TransferHook,
+        ];
+        let space = ExtensionType::try_calculate_account_len::<Mint>(&types).unwrap();
+        let mut data = vec![0u8; space];
+
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        state
+            .init_extension::<PermanentDelegate>(true)
+            .unwrap()
+            .delegate = Some(delegate).try_into().unwrap();
+        state
+            .init_extension::<TransferHook>(true)
+            .unwrap()
+            .program_id = Some(hook_program).try_into().unwrap();
+        state.base = Mint {
+            is_initialized: true,
+            ..Mint::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+
+        let mint = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+        assert_eq!(
+            decode(&mint),
+            vec![
+                MintExtension::PermanentDelegate {
+                    delegate: Some(delegate)
+                },
+                MintExtension::TransferHook {
+                    authority: None,
+                    program_id: Some(hook_program)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legacy_mint_has_no_extensions() {
+        let mut data = vec![0u8; Mint::LEN];
+        Mint {
+            is_initialized: true,
+            ..Mint::default()
+        }
+        .pack_into_slice(&mut data);
+
+        let mint = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+        assert!(decode(&mint).is_empty());
+    }
+}
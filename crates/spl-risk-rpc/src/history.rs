@@ -0,0 +1,340 @@
+use crate::solana::{LEGACY_TOKEN_PROGRAM_ID, SYSTEM_PROGRAM_ID};
+use solana_program::program_option::COption;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use solana_transaction_status::option_serializer::OptionSerializer;
+use solana_transaction_status::{
+    UiInstruction, UiTransactionStatusMeta, UiTransactionTokenBalance,
+};
+use spl_risk_core::model::token::AuthorityAction;
+use spl_token_2022::instruction::TokenInstruction;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// An instruction with its program and accounts resolved to addresses
+pub struct ResolvedInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<Pubkey>,
+    pub data: Vec<u8>,
+}
+
+/// Every instruction of a transaction in execution order: each top-level
+/// instruction followed by the CPIs it made.
+///
+/// Inner instructions are only known from `meta`; their account indices may point
+/// into the transaction's loaded lookup-table addresses.
+pub fn instructions(
+    transaction: &VersionedTransaction,
+    meta: Option<&UiTransactionStatusMeta>,
+) -> Vec<ResolvedInstruction> {
+    let mut keys = transaction.message.static_account_keys().to_vec();
+    if let Some(OptionSerializer::Some(loaded)) = meta.map(|meta| &meta.loaded_addresses) {
+        keys.extend(
+            loaded
+                .writable
+                .iter()
+                .chain(&loaded.readonly)
+                .filter_map(|key| Pubkey::from_str(key).ok()),
+        );
+    }
+
+    let resolve = |program: u8, accounts: &[u8], data: Vec<u8>| {
+        Some(ResolvedInstruction {
+            program_id: *keys.get(program as usize)?,
+            accounts: accounts
+                .iter()
+                .map(|&index| keys.get(index as usize).copied())
+                .collect::<Option<Vec<_>>>()?,
+            data,
+        })
+    };
+
+    let inner = match meta.map(|meta| &meta.inner_instructions) {
+        Some(OptionSerializer::Some(inner)) => inner.as_slice(),
+        _ => &[],
+    };
+
+    let mut resolved = Vec::new();
+    for (index, ix) in transaction.message.instructions().iter().enumerate() {
+        resolved.extend(resolve(ix.program_id_index, &ix.accounts, ix.data.clone()));
+
+        let cpis = inner
+            .iter()
+            .filter(|group| group.index as usize == index)
+            .flat_map(|group| &group.instructions);
+        for cpi in cpis {
+            let UiInstruction::Compiled(cpi) = cpi else {
+                continue;
+            };
+            if let Ok(data) = bs58::decode(&cpi.data).into_vec() {
+                resolved.extend(resolve(cpi.program_id_index, &cpi.accounts, data));
+            }
+        }
+    }
+
+    resolved
+}
+
+/// Authority-related token instructions on `mint`, in execution order
+pub fn decode_actions(
+    transaction: &VersionedTransaction,
+    meta: Option<&UiTransactionStatusMeta>,
+    mint: &Pubkey,
+) -> Vec<AuthorityAction> {
+    instructions(transaction, meta)
+        .iter()
+        .filter(|ix| {
+            ix.program_id == LEGACY_TOKEN_PROGRAM_ID || ix.program_id == spl_token_2022::id()
+        })
+        .filter_map(|ix| decode_action(ix, mint))
+        .collect()
+}
+
+/// Owners whose balance of `mint` grew in the transaction, with the amount received,
+/// from the token balances in `meta`
+pub fn token_receipts(meta: &UiTransactionStatusMeta, mint: &Pubkey) -> Vec<(Pubkey, u64)> {
+    let mint = mint.to_string();
+    let mut changes: BTreeMap<Pubkey, i128> = BTreeMap::new();
+
+    let mut apply = |balances: &OptionSerializer<Vec<UiTransactionTokenBalance>>, sign: i128| {
+        let OptionSerializer::Some(balances) = balances else {
+            return;
+        };
+        for balance in balances.iter().filter(|balance| balance.mint == mint) {
+            let OptionSerializer::Some(owner) = &balance.owner else {
+                continue;
+            };
+            let (Ok(owner), Ok(amount)) = (
+                Pubkey::from_str(owner),
+                balance.ui_token_amount.amount.parse::<u64>(),
+            ) else {
+                continue;
+            };
+            *changes.entry(owner).or_default() += sign * amount as i128;
+        }
+    };
+    apply(&meta.pre_token_balances, -1);
+    apply(&meta.post_token_balances, 1);
+
+    changes
+        .into_iter()
+        .filter(|(_, change)| *change > 0)
+        .map(|(owner, change)| (owner, u64::try_from(change).unwrap_or(u64::MAX)))
+        .collect()
+}
+
+/// System program `Transfer` and `TransferWithSeed` instruction tags
+const SYSTEM_TRANSFER: u32 = 2;
+const SYSTEM_TRANSFER_WITH_SEED: u32 = 11;
+
+/// Sender of the first system program SOL transfer into `wallet`, top-level or CPI
+pub fn funding_source(
+    transaction: &VersionedTransaction,
+    meta: Option<&UiTransactionStatusMeta>,
+    wallet: &Pubkey,
+) -> Option<Pubkey> {
+    instructions(transaction, meta)
+        .iter()
+        .filter(|ix| ix.program_id == SYSTEM_PROGRAM_ID && ix.data.len() >= 12)
+        .find_map(|ix| {
+            let tag = u32::from_le_bytes(ix.data[..4].try_into().ok()?);
+            let (from, to) = match tag {
+                SYSTEM_TRANSFER => (ix.accounts.first()?, ix.accounts.get(1)?),
+                SYSTEM_TRANSFER_WITH_SEED => (ix.accounts.first()?, ix.accounts.get(2)?),
+                _ => return None,
+            };
+            (to == wallet && from != wallet).then_some(*from)
+        })
+}
+
+fn decode_action(ix: &ResolvedInstruction, mint: &Pubkey) -> Option<AuthorityAction> {
+    let account = |index: usize| ix.accounts.get(index).copied();
+    let optional = |key: COption<Pubkey>| Option::<Pubkey>::from(key);
+
+    let action = match TokenInstruction::unpack(&ix.data).ok()? {
+        TokenInstruction::InitializeMint {
+            mint_authority,
+            freeze_authority,
+            ..
+        }
+        | TokenInstruction::InitializeMint2 {
+            mint_authority,
+            freeze_authority,
+            ..
+        } if account(0)? == *mint => AuthorityAction::InitializeMint {
+            mint_authority: Some(mint_authority),
+            freeze_authority: optional(freeze_authority),
+        },
+        // Also used on token accounts (owner, close authority); only the mint's count
+        TokenInstruction::SetAuthority {
+            authority_type,
+            new_authority,
+        } if account(0)? == *mint => AuthorityAction::SetAuthority {
+            authority_type: format!("{:?}", authority_type),
+            new_authority: optional(new_authority),
+            new_authority_first_seen: None,
+        },
+        TokenInstruction::MintTo { amount } | TokenInstruction::MintToChecked { amount, .. }
+            if account(0)? == *mint =>
+        {
+            AuthorityAction::MintTo {
+                amount,
+                destination: account(1)?,
+            }
+        }
+        TokenInstruction::FreezeAccount if account(1)? == *mint => AuthorityAction::Freeze {
+            account: account(0)?,
+        },
+        TokenInstruction::ThawAccount if account(1)? == *mint => AuthorityAction::Thaw {
+            account: account(0)?,
+        },
+        _ => return None,
+    };
+
+    Some(action)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::message::{Message, VersionedMessage};
+    use spl_token_2022::instruction::AuthorityType;
+    use spl_token_2022::instruction::{freeze_account, initialize_mint2, mint_to, set_authority};
+
+    #[test]
+    fn test_decodes_authority_actions_on_the_mint() {
+        let program = spl_token_2022::id();
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let account = Pubkey::new_unique();
+        let fresh = Pubkey::new_unique();
+
+        let instructions = [
+            initialize_mint2(&program, &mint, &payer, Some(&payer), 6).unwrap(),
+            mint_to(&program, &mint, &account, &payer, &[], 1_000).unwrap(),
+            set_authority(
+                &program,
+                &mint,
+                Some(&fresh),
+                AuthorityType::MintTokens,
+                &payer,
+                &[],
+            )
+            .unwrap(),
+            freeze_account(&program, &account, &mint, &payer, &[]).unwrap(),
+            // Owner change of a token account, not a mint authority
+            set_authority(
+                &program,
+                &account,
+                Some(&fresh),
+                AuthorityType::AccountOwner,
+                &payer,
+                &[],
+            )
+            .unwrap(),
+        ];
+        let message = Message::new(&instructions, Some(&payer));
+        let transaction = VersionedTransaction {
+            signatures: vec![Default::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::Legacy(message),
+        };
+
+        assert_eq!(funding_source(&transaction, None, &fresh), None);
+        assert_eq!(
+            decode_actions(&transaction, None, &mint),
+            vec![
+                AuthorityAction::InitializeMint {
+                    mint_authority: Some(payer),
+                    freeze_authority: Some(payer),
+                },
+                AuthorityAction::MintTo {
+                    amount: 1_000,
+                    destination: account,
+                },
+                AuthorityAction::SetAuthority {
+                    authority_type: "MintTokens".to_string(),
+                    new_authority: Some(fresh),
+                    new_authority_first_seen: None,
+                },
+                AuthorityAction::Freeze { account },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_funding_source_is_first_transfer_in() {
+        let funder = Pubkey::new_unique();
+        let wallet = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+
+        let transfer = |from: &Pubkey, to: &Pubkey, lamports: u64| {
+            let mut data = SYSTEM_TRANSFER.to_le_bytes().to_vec();
+            data.extend_from_slice(&lamports.to_le_bytes());
+            solana_sdk::instruction::Instruction::new_with_bytes(
+                SYSTEM_PROGRAM_ID,
+                &data,
+                vec![
+                    solana_sdk::instruction::AccountMeta::new(*from, true),
+                    solana_sdk::instruction::AccountMeta::new(*to, false),
+                ],
+            )
+        };
+
+        let instructions = [
+            transfer(&wallet, &other, 1),
+            transfer(&funder, &wallet, 1_000_000),
+            transfer(&other, &wallet, 5),
+        ];
+        let message = Message::new(&instructions, Some(&funder));
+        let transaction = VersionedTransaction {
+            signatures: vec![Default::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::Legacy(message),
+        };
+
+        assert_eq!(funding_source(&transaction, None, &wallet), Some(funder));
+        assert_eq!(funding_source(&transaction, None, &funder), None);
+    }
+
+    #[test]
+    fn test_token_receipts_are_balance_increases() {
+        let mint = Pubkey::new_unique();
+        let pool = Pubkey::new_unique();
+        let buyer = Pubkey::new_unique();
+        let other_mint = Pubkey::new_unique();
+
+        let balance = |index: u8, mint: &Pubkey, owner: &Pubkey, amount: u64| {
+            serde_json::json!({
+                "accountIndex": index,
+                "mint": mint.to_string(),
+                "owner": owner.to_string(),
+                "uiTokenAmount": {
+                    "amount": amount.to_string(),
+                    "decimals": 6,
+                    "uiAmount": null,
+                    "uiAmountString": "0",
+                },
+            })
+        };
+        let meta: UiTransactionStatusMeta = serde_json::from_value(serde_json::json!({
+            "err": null,
+            "status": { "Ok": null },
+            "fee": 5000,
+            "preBalances": [],
+            "postBalances": [],
+            "preTokenBalances": [
+                balance(1, &mint, &pool, 1_000),
+                balance(3, &other_mint, &buyer, 50),
+            ],
+            "postTokenBalances": [
+                balance(1, &mint, &pool, 700),
+                // The buyer's account is created in the transaction
+                balance(2, &mint, &buyer, 300),
+                balance(3, &other_mint, &buyer, 0),
+            ],
+        }))
+        .unwrap();
+
+        assert_eq!(token_receipts(&meta, &mint), vec![(buyer, 300)]);
+        assert!(token_receipts(&meta, &other_mint).is_empty());
+    }
+}
@@ -0,0 +1,79 @@
+use crate::solana::SYSTEM_PROGRAM_ID;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::labels::{HolderKind, HolderLabel, LabelRegistry};
+
+/// Label a holder that isn't in the registry by its owner account. `account` is
+/// `None` when the owner doesn't exist on chain.
+///
+/// Owners held by a registry program are labeled after that program. Other
+/// program-owned accounts and PDAs are labeled as unknown programs, which still
+/// count towards concentration. Keypair wallets get no label.
+pub fn label_owner(
+    registry: &LabelRegistry,
+    owner: &Pubkey,
+    account: Option<&Account>,
+) -> Option<HolderLabel> {
+    let program_account = HolderLabel {
+        kind: HolderKind::UnknownProgram,
+        name: None,
+    };
+
+    match account {
+        Some(account) if account.owner != SYSTEM_PROGRAM_ID => Some(
+            registry
+                .program(&account.owner)
+                .cloned()
+                .unwrap_or(program_account),
+        ),
+        _ if owner.is_on_curve() => None,
+        _ => Some(program_account),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sdk::signer::Signer;
+    use std::str::FromStr;
+
+    fn account(owner: Pubkey) -> Account {
+        Account {
+            lamports: 1_000_000,
+            data: vec![0; 64],
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    #[test]
+    fn test_labels_program_owned_holders() {
+        let registry = LabelRegistry::builtin();
+        let (curve, _) = Pubkey::find_program_address(&[b"bonding-curve"], &Pubkey::new_unique());
+
+        let pump = Pubkey::from_str("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P").unwrap();
+        assert_eq!(
+            label_owner(&registry, &curve, Some(&account(pump))).map(|label| label.kind),
+            Some(HolderKind::Curve)
+        );
+        assert_eq!(
+            label_owner(&registry, &curve, Some(&account(Pubkey::new_unique()))),
+            Some(HolderLabel {
+                kind: HolderKind::UnknownProgram,
+                name: None,
+            })
+        );
+        assert_eq!(
+            label_owner(&registry, &curve, None).map(|label| label.kind),
+            Some(HolderKind::UnknownProgram)
+        );
+
+        let wallet = solana_sdk::signature::Keypair::new().pubkey();
+        assert_eq!(label_owner(&registry, &wallet, None), None);
+        assert_eq!(
+            label_owner(&registry, &wallet, Some(&account(SYSTEM_PROGRAM_ID))),
+            None
+        );
+    }
+}
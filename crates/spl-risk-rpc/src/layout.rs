@@ -0,0 +1,50 @@
+// Little-endian field readers for fixed account layouts; `None` when the
+// field runs past the end of the data
+
+use solana_sdk::pubkey::Pubkey;
+
+pub(crate) fn pubkey_at(data: &[u8], offset: usize) -> Option<Pubkey> {
+    Pubkey::try_from(data.get(offset..offset + 32)?).ok()
+}
+
+pub(crate) fn u16_at(data: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        data.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+pub(crate) fn u32_at(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+pub(crate) fn i32_at(data: &[u8], offset: usize) -> Option<i32> {
+    Some(i32::from_le_bytes(
+        data.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+pub(crate) fn u64_at(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+pub(crate) fn i64_at(data: &[u8], offset: usize) -> Option<i64> {
+    Some(i64::from_le_bytes(
+        data.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+pub(crate) fn u128_at(data: &[u8], offset: usize) -> Option<u128> {
+    Some(u128::from_le_bytes(
+        data.get(offset..offset + 16)?.try_into().ok()?,
+    ))
+}
+
+/// Write `bytes` into a test account at `offset`
+#[cfg(test)]
+pub(crate) fn put(data: &mut [u8], offset: usize, bytes: &[u8]) {
+    data[offset..offset + bytes.len()].copy_from_slice(bytes);
+}
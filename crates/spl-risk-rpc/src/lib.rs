@@ -1,5 +1,30 @@
 mod solana;
 pub use solana::SolanaRpcClient;
 
+mod pool;
+pub use pool::EndpointStats;
+
+mod authority;
+mod creator;
+mod curve;
+mod extensions;
+mod history;
+mod labels;
+// Most readers serve the LP decoders only
+#[cfg_attr(not(feature = "lp-analysis"), allow(dead_code))]
+mod layout;
+#[cfg(feature = "lp-analysis")]
+mod liquidity;
+#[cfg(feature = "lp-analysis")]
+mod locker;
+mod metadata;
+#[cfg(feature = "lp-analysis")]
+mod price;
+mod quorum;
+mod simulation;
+
+mod policy;
+pub use policy::{ErrorClass, RetryOn, RpcPolicy};
+
 mod cache;
 pub use cache::{Cache, CacheStats};
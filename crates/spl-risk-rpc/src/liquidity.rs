@@ -0,0 +1,452 @@
+use crate::layout::{i32_at, pubkey_at, u128_at, u16_at, u64_at};
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::{PoolKind, PositionOwnership};
+
+pub const RAYDIUM_AMM_V4_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("675kPX9MHTjS2zt1qfr1NYHuzeLXfQM9H24wFSUt1Mp8");
+pub const RAYDIUM_CPMM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("CPMMoo8L3F4NbTegBCKVNunggL7H1ZpdTHKxQB5qKP1C");
+pub const ORCA_WHIRLPOOL_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("whirLbMiicVdio4qvUfM5KAg6Ct8VwpYzGff3uctyCc");
+pub const METEORA_DLMM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("LBUZKhRxPF3XUpBCjp4YzTKgLccjZhTSDM9YuVaPwxo");
+
+/// Account layout of a pool program: size and where the two mints sit
+pub struct PoolProgram {
+    pub dex: &'static str,
+    pub kind: PoolKind,
+    pub program: Pubkey,
+    pub size: u64,
+    pub mint_offsets: [usize; 2],
+    decode: fn(&[u8]) -> Option<PoolLayout>,
+    /// Position accounts of concentrated-liquidity pools
+    pub positions: Option<PositionProgram>,
+}
+
+/// Position accounts of a concentrated-liquidity program, found by their pool
+pub struct PositionProgram {
+    pub size: u64,
+    pub pool_offset: usize,
+    /// Part of the account `decode` reads: holder and liquidity
+    pub slice: (usize, usize),
+    decode: fn(&[u8]) -> Option<(PositionHolder, u128)>,
+}
+
+/// Who a position belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PositionHolder {
+    /// Whoever holds this position NFT
+    Nft(Pubkey),
+    /// Recorded in the position account
+    Owner(Pubkey),
+}
+
+/// Raydium AMM v4 `AmmInfo` (752 bytes), Raydium CPMM `PoolState` (637 bytes),
+/// Orca `Whirlpool` (653 bytes) and Meteora DLMM `LbPair` (904 bytes)
+pub const POOL_PROGRAMS: &[PoolProgram] = &[
+    PoolProgram {
+        dex: "raydium_amm_v4",
+        kind: PoolKind::ConstantProduct,
+        program: RAYDIUM_AMM_V4_PROGRAM_ID,
+        size: 752,
+        mint_offsets: [400, 432],
+        decode: decode_amm_v4,
+        positions: None,
+    },
+    PoolProgram {
+        dex: "raydium_cpmm",
+        kind: PoolKind::ConstantProduct,
+        program: RAYDIUM_CPMM_PROGRAM_ID,
+        size: 637,
+        mint_offsets: [168, 200],
+        decode: decode_cpmm,
+        positions: None,
+    },
+    PoolProgram {
+        dex: "orca_whirlpool",
+        kind: PoolKind::Concentrated,
+        program: ORCA_WHIRLPOOL_PROGRAM_ID,
+        size: 653,
+        mint_offsets: [101, 181],
+        decode: decode_whirlpool,
+        // `Position`: whirlpool, position_mint, liquidity (u128)
+        positions: Some(PositionProgram {
+            size: 216,
+            pool_offset: 8,
+            slice: (40, 48),
+            decode: decode_whirlpool_position,
+        }),
+    },
+    PoolProgram {
+        dex: "meteora_dlmm",
+        kind: PoolKind::Concentrated,
+        program: METEORA_DLMM_PROGRAM_ID,
+        size: 904,
+        mint_offsets: [88, 120],
+        decode: decode_dlmm,
+        // `PositionV2`: lb_pair, owner, liquidity_shares ([u128; 70])
+        positions: Some(PositionProgram {
+            size: 8120,
+            pool_offset: 8,
+            slice: (40, 32 + 70 * 16),
+            decode: decode_dlmm_position,
+        }),
+    },
+];
+
+/// How a pool prices its tokens
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PriceModel {
+    /// Ratio of the reserves
+    Reserves,
+    /// Square root of the price of side 0 in side 1 base units, Q64.64
+    SqrtPriceX64(u128),
+    /// Active bin of a bin-based pool; the price of side 0 in side 1 base units
+    /// is `(1 + bin_step / 10000) ^ active_id`
+    Bin { active_id: i32, bin_step: u16 },
+}
+
+/// The fields of a pool account needed to value it and its liquidity.
+/// Sides are in the pool's order; `pending` is what the vaults hold beyond the
+/// reserves (fees and PnL not yet taken).
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoolLayout {
+    pub mints: [Pubkey; 2],
+    pub vaults: [Pubkey; 2],
+    pub pending: [u64; 2],
+    pub price: PriceModel,
+    /// LP mint and LP tokens issued, for pools with a fungible LP token
+    pub lp: Option<(Pubkey, u64)>,
+    /// Wallet that opened the pool, when the program records it
+    pub creator: Option<Pubkey>,
+}
+
+impl PoolProgram {
+    pub fn decode(&self, data: &[u8]) -> Option<PoolLayout> {
+        if data.len() as u64 != self.size {
+            return None;
+        }
+        (self.decode)(data)
+    }
+}
+
+impl PositionProgram {
+    /// Decode the `slice` of a position account
+    pub fn decode(&self, data: &[u8]) -> Option<(PositionHolder, u128)> {
+        if data.len() != self.slice.1 {
+            return None;
+        }
+        (self.decode)(data)
+    }
+}
+
+/// The pool program behind a `LiquidityPool::dex` name
+pub fn program(dex: &str) -> Option<&'static PoolProgram> {
+    POOL_PROGRAMS.iter().find(|program| program.dex == dex)
+}
+
+fn decode_amm_v4(data: &[u8]) -> Option<PoolLayout> {
+    Some(PoolLayout {
+        mints: [pubkey_at(data, 400)?, pubkey_at(data, 432)?],
+        vaults: [pubkey_at(data, 336)?, pubkey_at(data, 368)?],
+        // need_take_pnl_coin / need_take_pnl_pc
+        pending: [u64_at(data, 192)?, u64_at(data, 200)?],
+        price: PriceModel::Reserves,
+        lp: Some((pubkey_at(data, 464)?, u64_at(data, 720)?)),
+        creator: None,
+    })
+}
+
+fn decode_cpmm(data: &[u8]) -> Option<PoolLayout> {
+    let fees = |protocol: usize, fund: usize| {
+        Some(u64_at(data, protocol)?.saturating_add(u64_at(data, fund)?))
+    };
+
+    Some(PoolLayout {
+        mints: [pubkey_at(data, 168)?, pubkey_at(data, 200)?],
+        vaults: [pubkey_at(data, 72)?, pubkey_at(data, 104)?],
+        pending: [fees(341, 357)?, fees(349, 365)?],
+        price: PriceModel::Reserves,
+        lp: Some((pubkey_at(data, 136)?, u64_at(data, 333)?)),
+        creator: pubkey_at(data, 40),
+    })
+}
+
+fn decode_whirlpool(data: &[u8]) -> Option<PoolLayout> {
+    Some(PoolLayout {
+        mints: [pubkey_at(data, 101)?, pubkey_at(data, 181)?],
+        vaults: [pubkey_at(data, 133)?, pubkey_at(data, 213)?],
+        // protocol_fee_owed_a / protocol_fee_owed_b
+        pending: [u64_at(data, 85)?, u64_at(data, 93)?],
+        price: PriceModel::SqrtPriceX64(u128_at(data, 65)?),
+        lp: None,
+        creator: None,
+    })
+}
+
+fn decode_dlmm(data: &[u8]) -> Option<PoolLayout> {
+    Some(PoolLayout {
+        mints: [pubkey_at(data, 88)?, pubkey_at(data, 120)?],
+        vaults: [pubkey_at(data, 152)?, pubkey_at(data, 184)?],
+        // protocol_fee.amount_x / amount_y
+        pending: [u64_at(data, 216)?, u64_at(data, 224)?],
+        price: PriceModel::Bin {
+            active_id: i32_at(data, 76)?,
+            bin_step: u16_at(data, 80)?,
+        },
+        lp: None,
+        creator: pubkey_at(data, 848).filter(|creator| *creator != Pubkey::default()),
+    })
+}
+
+fn decode_whirlpool_position(data: &[u8]) -> Option<(PositionHolder, u128)> {
+    Some((PositionHolder::Nft(pubkey_at(data, 0)?), u128_at(data, 32)?))
+}
+
+fn decode_dlmm_position(data: &[u8]) -> Option<(PositionHolder, u128)> {
+    // Shares of different bins aren't worth the same, but their sum ranks positions
+    let shares = data
+        .get(32..)?
+        .chunks_exact(16)
+        .map(|share| u128::from_le_bytes(share.try_into().expect("16-byte chunk")))
+        .fold(0u128, u128::saturating_add);
+    Some((PositionHolder::Owner(pubkey_at(data, 0)?), shares))
+}
+
+/// Price of one whole `side` token in whole tokens of the other side
+pub fn price(model: PriceModel, side: usize, decimals: [u8; 2], reserves: [u64; 2]) -> Option<f64> {
+    let scale = 10f64.powi(decimals[0] as i32 - decimals[1] as i32);
+    // Whole side-1 tokens per whole side-0 token
+    let price0 = match model {
+        PriceModel::Reserves => {
+            if reserves[0] == 0 {
+                return None;
+            }
+            reserves[1] as f64 / reserves[0] as f64 * scale
+        }
+        PriceModel::SqrtPriceX64(sqrt_price) => {
+            let sqrt_price = sqrt_price as f64 / 2f64.powi(64);
+            sqrt_price * sqrt_price * scale
+        }
+        PriceModel::Bin {
+            active_id,
+            bin_step,
+        } => (1.0 + bin_step as f64 / 10_000.0).powi(active_id) * scale,
+    };
+
+    let price = if side == 0 { price0 } else { 1.0 / price0 };
+    (price.is_finite() && price > 0.0).then_some(price)
+}
+
+/// Value of the pool in quote tokens: the quote reserve plus the token reserve at `price`
+pub fn value_in_quote(token_reserve: f64, quote_reserve: f64, price: Option<f64>) -> f64 {
+    quote_reserve + price.map_or(0.0, |price| token_reserve * price)
+}
+
+/// Creator and locker shares of a pool's liquidity from its positions: liquidity,
+/// owner when it was resolved, and whether the owner is a known locker.
+/// Positions without a resolved owner count towards the total only.
+pub fn position_ownership(
+    positions: &[(u128, Option<Pubkey>, bool)],
+    creator: Option<&Pubkey>,
+) -> PositionOwnership {
+    let total: f64 = positions
+        .iter()
+        .map(|(liquidity, _, _)| *liquidity as f64)
+        .sum();
+    let share = |owned: &dyn Fn(&Option<Pubkey>, bool) -> bool| {
+        if total == 0.0 {
+            return 0.0;
+        }
+        let owned: f64 = positions
+            .iter()
+            .filter(|(_, owner, locked)| owned(owner, *locked))
+            .map(|(liquidity, _, _)| *liquidity as f64)
+            .sum();
+        owned / total * 100.0
+    };
+
+    PositionOwnership {
+        positions: positions.len(),
+        creator_pct: creator.map(|creator| share(&|owner, _| owner.as_ref() == Some(creator))),
+        locked_pct: share(&|_, locked| locked),
+    }
+}
+
+/// `amount` as a percentage of `supply`
+pub fn share_pct(amount: u64, supply: u64) -> f64 {
+    if supply == 0 {
+        return 0.0;
+    }
+    amount as f64 / supply as f64 * 100.0
+}
+
+/// Share of issued LP tokens that no longer exist
+pub fn burned_pct(lp_issued: u64, lp_supply: u64) -> f64 {
+    if lp_issued == 0 {
+        return 0.0;
+    }
+    lp_issued.saturating_sub(lp_supply) as f64 / lp_issued as f64 * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::put;
+
+    #[test]
+    fn test_decodes_raydium_pools() {
+        let (mint, quote, lp_mint) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let (vault_a, vault_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut amm = vec![0u8; 752];
+        put(&mut amm, 200, &7u64.to_le_bytes());
+        put(&mut amm, 336, vault_a.as_ref());
+        put(&mut amm, 368, vault_b.as_ref());
+        put(&mut amm, 400, mint.as_ref());
+        put(&mut amm, 432, quote.as_ref());
+        put(&mut amm, 464, lp_mint.as_ref());
+        put(&mut amm, 720, &1_000u64.to_le_bytes());
+
+        let layout = program("raydium_amm_v4").unwrap().decode(&amm).unwrap();
+        assert_eq!(layout.mints, [mint, quote]);
+        assert_eq!(layout.vaults, [vault_a, vault_b]);
+        assert_eq!(layout.pending, [0, 7]);
+        assert_eq!(layout.lp, Some((lp_mint, 1_000)));
+        assert_eq!(layout.creator, None);
+        // Wrong size for the program
+        assert_eq!(program("raydium_cpmm").unwrap().decode(&amm), None);
+
+        let creator = Pubkey::new_unique();
+        let mut cpmm = vec![0u8; 637];
+        put(&mut cpmm, 40, creator.as_ref());
+        put(&mut cpmm, 72, vault_b.as_ref());
+        put(&mut cpmm, 104, vault_a.as_ref());
+        put(&mut cpmm, 136, lp_mint.as_ref());
+        put(&mut cpmm, 168, quote.as_ref());
+        put(&mut cpmm, 200, mint.as_ref());
+        put(&mut cpmm, 333, &500u64.to_le_bytes());
+        put(&mut cpmm, 341, &3u64.to_le_bytes());
+        put(&mut cpmm, 357, &2u64.to_le_bytes());
+
+        let layout = program("raydium_cpmm").unwrap().decode(&cpmm).unwrap();
+        assert_eq!(layout.mints, [quote, mint]);
+        assert_eq!(layout.vaults, [vault_b, vault_a]);
+        assert_eq!(layout.pending, [5, 0]);
+        assert_eq!(layout.lp, Some((lp_mint, 500)));
+        assert_eq!(layout.creator, Some(creator));
+    }
+
+    #[test]
+    fn test_decodes_concentrated_pools() {
+        let (mint_a, mint_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let (vault_a, vault_b) = (Pubkey::new_unique(), Pubkey::new_unique());
+
+        let mut whirlpool = vec![0u8; 653];
+        put(&mut whirlpool, 65, &(1u128 << 64).to_le_bytes());
+        put(&mut whirlpool, 93, &4u64.to_le_bytes());
+        put(&mut whirlpool, 101, mint_a.as_ref());
+        put(&mut whirlpool, 133, vault_a.as_ref());
+        put(&mut whirlpool, 181, mint_b.as_ref());
+        put(&mut whirlpool, 213, vault_b.as_ref());
+
+        let layout = program("orca_whirlpool")
+            .unwrap()
+            .decode(&whirlpool)
+            .unwrap();
+        assert_eq!(layout.mints, [mint_a, mint_b]);
+        assert_eq!(layout.vaults, [vault_a, vault_b]);
+        assert_eq!(layout.pending, [0, 4]);
+        assert_eq!(layout.price, PriceModel::SqrtPriceX64(1 << 64));
+        assert_eq!(layout.lp, None);
+
+        let creator = Pubkey::new_unique();
+        let mut dlmm = vec![0u8; 904];
+        put(&mut dlmm, 76, &(-25i32).to_le_bytes());
+        put(&mut dlmm, 80, &80u16.to_le_bytes());
+        put(&mut dlmm, 88, mint_a.as_ref());
+        put(&mut dlmm, 120, mint_b.as_ref());
+        put(&mut dlmm, 152, vault_a.as_ref());
+        put(&mut dlmm, 184, vault_b.as_ref());
+        put(&mut dlmm, 848, creator.as_ref());
+
+        let layout = program("meteora_dlmm").unwrap().decode(&dlmm).unwrap();
+        assert_eq!(layout.mints, [mint_a, mint_b]);
+        assert_eq!(layout.vaults, [vault_a, vault_b]);
+        assert_eq!(
+            layout.price,
+            PriceModel::Bin {
+                active_id: -25,
+                bin_step: 80,
+            }
+        );
+        assert_eq!(layout.creator, Some(creator));
+
+        let positions = program("meteora_dlmm").unwrap().positions.as_ref().unwrap();
+        let owner = Pubkey::new_unique();
+        let mut slice = vec![0u8; positions.slice.1];
+        put(&mut slice, 0, owner.as_ref());
+        put(&mut slice, 32, &5u128.to_le_bytes());
+        put(&mut slice, 32 + 69 * 16, &7u128.to_le_bytes());
+        assert_eq!(
+            positions.decode(&slice),
+            Some((PositionHolder::Owner(owner), 12))
+        );
+    }
+
+    #[test]
+    fn test_prices_and_values() {
+        // 1,000 tokens (6 decimals) against 50 SOL (9 decimals)
+        let reserves = [1_000_000_000, 50_000_000_000];
+        let price = price(PriceModel::Reserves, 0, [6, 9], reserves).unwrap();
+        assert!((price - 0.05).abs() < 1e-12);
+
+        // sqrt price 2.0 in base units: 4 side-1 units per side-0 unit
+        let model = PriceModel::SqrtPriceX64(2u128 << 64);
+        assert!((super::price(model, 0, [6, 6], [0, 0]).unwrap() - 4.0).abs() < 1e-12);
+        assert!((super::price(model, 1, [6, 6], [0, 0]).unwrap() - 0.25).abs() < 1e-12);
+        assert!((super::price(model, 0, [9, 6], [0, 0]).unwrap() - 4_000.0).abs() < 1e-9);
+
+        let model = PriceModel::Bin {
+            active_id: 100,
+            bin_step: 10,
+        };
+        let expected = 1.001f64.powi(100);
+        assert!((super::price(model, 0, [6, 6], [0, 0]).unwrap() - expected).abs() < 1e-12);
+
+        assert_eq!(super::price(PriceModel::Reserves, 0, [6, 6], [0, 5]), None);
+
+        assert_eq!(value_in_quote(1_000.0, 50.0, Some(0.05)), 100.0);
+    }
+
+    #[test]
+    fn test_position_ownership() {
+        let (creator, locker) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let positions = [
+            (600, Some(locker), true),
+            (300, Some(creator), false),
+            (100, None, false),
+        ];
+
+        let ownership = position_ownership(&positions, Some(&creator));
+        assert_eq!(ownership.positions, 3);
+        assert!((ownership.creator_pct.unwrap() - 30.0).abs() < 1e-9);
+        assert!((ownership.locked_pct - 60.0).abs() < 1e-9);
+
+        let ownership = position_ownership(&[], None);
+        assert_eq!(ownership.creator_pct, None);
+        assert_eq!(ownership.locked_pct, 0.0);
+    }
+
+    #[test]
+    fn test_lp_burn() {
+        assert_eq!(burned_pct(0, 0), 0.0);
+        assert!((burned_pct(1_000, 10) - 99.0).abs() < 1e-9);
+        // More supply than the pool recorded (e.g. the locked minimum)
+        assert_eq!(burned_pct(1_000, 1_100), 0.0);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::layout::{pubkey_at, u64_at};
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::VestingSchedule;
+
+pub const STREAMFLOW_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("strmRqUCoQUgGUan5YhzUZa6KqdzwX5L6FpUxfmKg5m");
+pub const JUPITER_LOCK_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("LocpQgucEQHbqNABEYvBvwoxCPsSbG91A1QaQhQQqjn");
+
+/// Anchor discriminator of Jupiter Lock's `VestingEscrow` account
+const VESTING_ESCROW_DISCRIMINATOR: [u8; 8] = [244, 119, 183, 4, 73, 116, 135, 195];
+
+/// Lock accounts of a locker program, found by the mint they lock
+pub struct LockerProgram {
+    pub name: &'static str,
+    pub program: Pubkey,
+    pub size: u64,
+    pub mint_offset: usize,
+    decode: fn(&[u8]) -> Option<LockTerms>,
+}
+
+/// What a lock account says about the tokens it holds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LockTerms {
+    pub beneficiary: Pubkey,
+    pub schedule: VestingSchedule,
+}
+
+/// Streamflow `Contract` (1104 bytes) and Jupiter Lock `VestingEscrow` (296 bytes)
+pub const LOCKER_PROGRAMS: &[LockerProgram] = &[
+    LockerProgram {
+        name: "Streamflow",
+        program: STREAMFLOW_PROGRAM_ID,
+        size: 1104,
+        mint_offset: 177,
+        decode: decode_streamflow,
+    },
+    LockerProgram {
+        name: "Jupiter Lock",
+        program: JUPITER_LOCK_PROGRAM_ID,
+        size: 296,
+        mint_offset: 40,
+        decode: decode_jupiter_lock,
+    },
+];
+
+impl LockerProgram {
+    /// `None` for accounts of another size, empty locks and locks the sender
+    /// cancelled or can still cancel: a creator who can cancel can take the
+    /// tokens back at any time, so they aren't locked.
+    pub fn decode(&self, data: &[u8]) -> Option<LockTerms> {
+        if data.len() as u64 != self.size {
+            return None;
+        }
+        (self.decode)(data).filter(|terms| terms.schedule.total > terms.schedule.withdrawn)
+    }
+}
+
+fn time_at(data: &[u8], offset: usize) -> Option<i64> {
+    i64::try_from(u64_at(data, offset)?).ok()
+}
+
+/// Streamflow streams (locks are streams that release everything at the end):
+/// withdrawn amount at 17, canceled_at at 25, recipient at 113, then the create
+/// parameters from 409: start time, deposited amount, period, amount per period,
+/// cliff, cliff amount and, at 457, `cancelable_by_sender`
+fn decode_streamflow(data: &[u8]) -> Option<LockTerms> {
+    if u64_at(data, 25)? != 0 || *data.get(457)? != 0 {
+        return None;
+    }
+
+    Some(LockTerms {
+        beneficiary: pubkey_at(data, 113)?,
+        schedule: VestingSchedule {
+            // A stream without a cliff starts releasing at its start time
+            cliff_at: time_at(data, 441)?.max(time_at(data, 409)?),
+            cliff_amount: u64_at(data, 449)?,
+            period: time_at(data, 425)?,
+            amount_per_period: u64_at(data, 433)?,
+            total: u64_at(data, 417)?,
+            withdrawn: u64_at(data, 17)?,
+        },
+    })
+}
+
+/// Jupiter Lock escrows: recipient at 8, `cancel_mode` at 138 (bit 0: the creator
+/// can cancel), then from 144 the cliff time, frequency, cliff amount, amount per
+/// period, number of periods, claimed amount and, at 200, `cancelled_at`
+fn decode_jupiter_lock(data: &[u8]) -> Option<LockTerms> {
+    if data.get(..8)? != VESTING_ESCROW_DISCRIMINATOR
+        || data.get(138)? & 1 != 0
+        || u64_at(data, 200)? != 0
+    {
+        return None;
+    }
+
+    let cliff_amount = u64_at(data, 160)?;
+    let amount_per_period = u64_at(data, 168)?;
+    let periods = u64_at(data, 176)?;
+
+    Some(LockTerms {
+        beneficiary: pubkey_at(data, 8)?,
+        schedule: VestingSchedule {
+            cliff_at: time_at(data, 144)?,
+            cliff_amount,
+            period: time_at(data, 152)?,
+            amount_per_period,
+            total: cliff_amount.saturating_add(amount_per_period.saturating_mul(periods)),
+            withdrawn: u64_at(data, 184)?,
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::put;
+
+    #[test]
+    fn test_decodes_streamflow_lock() {
+        let recipient = Pubkey::new_unique();
+        let start = 1_700_000_000u64;
+        let cliff = start + 10 * 86_400;
+        let mut data = vec![0u8; 1104];
+        put(&mut data, 17, &100u64.to_le_bytes());
+        put(&mut data, 113, recipient.as_ref());
+        put(&mut data, 409, &start.to_le_bytes());
+        put(&mut data, 417, &1_000u64.to_le_bytes());
+        put(&mut data, 425, &86_400u64.to_le_bytes());
+        put(&mut data, 433, &100u64.to_le_bytes());
+        put(&mut data, 441, &cliff.to_le_bytes());
+        put(&mut data, 449, &500u64.to_le_bytes());
+
+        let streamflow = &LOCKER_PROGRAMS[0];
+        let terms = streamflow.decode(&data).unwrap();
+        assert_eq!(terms.beneficiary, recipient);
+        let (schedule, cliff) = (terms.schedule, cliff as i64);
+        assert_eq!(schedule.locked_at(cliff - 1), 900);
+        // Half at the cliff, then 100 a day
+        assert_eq!(schedule.locked_at(cliff), 500);
+        assert_eq!(schedule.locked_at(cliff + 2 * 86_400), 300);
+        assert_eq!(schedule.end(), cliff + 5 * 86_400);
+        assert_eq!(schedule.locked_at(schedule.end()), 0);
+
+        // The sender can take the tokens back whenever they like
+        data[457] = 1;
+        assert_eq!(streamflow.decode(&data), None);
+        data[457] = 0;
+
+        // Cancelled streams returned their tokens
+        put(&mut data, 25, &1_700_000_000u64.to_le_bytes());
+        assert_eq!(streamflow.decode(&data), None);
+    }
+
+    #[test]
+    fn test_decodes_jupiter_lock() {
+        let recipient = Pubkey::new_unique();
+        let cliff = 1_700_000_000i64;
+        let mut data = vec![0u8; 296];
+        put(&mut data, 0, &VESTING_ESCROW_DISCRIMINATOR);
+        put(&mut data, 8, recipient.as_ref());
+        put(&mut data, 144, &(cliff as u64).to_le_bytes());
+        put(&mut data, 152, &86_400u64.to_le_bytes());
+        put(&mut data, 160, &500u64.to_le_bytes());
+        put(&mut data, 168, &100u64.to_le_bytes());
+        put(&mut data, 176, &5u64.to_le_bytes());
+        put(&mut data, 184, &1_000u64.to_le_bytes());
+
+        let jupiter = &LOCKER_PROGRAMS[1];
+        assert_eq!(jupiter.decode(&data), None, "everything was claimed");
+
+        put(&mut data, 184, &200u64.to_le_bytes());
+        let terms = jupiter.decode(&data).unwrap();
+        assert_eq!(terms.beneficiary, recipient);
+        assert_eq!(terms.schedule.total, 1_000);
+        assert_eq!(terms.schedule.locked_at(cliff - 1), 800);
+        assert_eq!(terms.schedule.locked_at(cliff + 86_400), 400);
+        assert_eq!(terms.schedule.end(), cliff + 5 * 86_400);
+        assert_eq!(LOCKER_PROGRAMS[0].decode(&data), None);
+
+        // Cancellable by the recipient only is still a lock; by the creator it isn't
+        data[138] = 2;
+        assert!(jupiter.decode(&data).is_some());
+        for mode in [1, 3] {
+            data[138] = mode;
+            assert_eq!(jupiter.decode(&data), None);
+        }
+    }
+}
@@ -0,0 +1,411 @@
+use mpl_token_metadata::accounts::Metadata;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::model::token::{
+    MetadataCollection, MetadataCreator, MetadataSource, TokenMetadata,
+};
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::TokenMetadata as Token2022Metadata;
+
+pub const METADATA_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
+
+/// Metaplex metadata PDA of a mint
+pub fn metadata_pda(mint: &Pubkey) -> Pubkey {
+    let seeds = &[b"metadata", METADATA_PROGRAM_ID.as_ref(), mint.as_ref()];
+    Pubkey::find_program_address(seeds, &METADATA_PROGRAM_ID).0
+}
+
+/// Decode a Metaplex metadata account.
+///
+/// Accounts that don't deserialize as the current `Metadata` layout (very old or
+/// truncated ones) still yield their display fields and update authority.
+pub fn decode_metadata(data: &[u8]) -> TokenMetadata {
+    match Metadata::from_bytes(data) {
+        Ok(metadata) => from_metaplex(metadata),
+        Err(_) => decode_display_fields(data),
+    }
+}
+
+/// Address named by the mint's `MetadataPointer`, if it has one
+pub fn metadata_pointer(mint: &StateWithExtensions<Mint>) -> Option<Pubkey> {
+    let pointer = mint.get_extension::<MetadataPointer>().ok()?;
+    Option::<Pubkey>::from(pointer.metadata_address)
+}
+
+/// Token-2022 metadata stored on the mint itself, i.e. when its `MetadataPointer`
+/// points back at the mint
+pub fn embedded_metadata(
+    address: &Pubkey,
+    mint: &StateWithExtensions<Mint>,
+) -> Option<TokenMetadata> {
+    if metadata_pointer(mint)? != *address {
+        return None;
+    }
+    token_metadata_extension(address, mint)
+}
+
+/// Decode the account `mint`'s `MetadataPointer` names elsewhere: either a Metaplex
+/// metadata account or another Token-2022 mint carrying the metadata extension.
+///
+/// Anyone can point at another token's metadata, so accounts describing a
+/// different mint are rejected.
+pub fn decode_pointed_metadata(mint: &Pubkey, account: &Account) -> Option<TokenMetadata> {
+    if account.owner == METADATA_PROGRAM_ID {
+        // key (1) | update_authority (32) | mint (32)
+        if account.data.get(33..65)? != mint.as_ref() {
+            return None;
+        }
+        return Some(decode_metadata(&account.data));
+    }
+    if account.owner != spl_token_2022::id() {
+        return None;
+    }
+
+    let state = StateWithExtensions::<Mint>::unpack(&account.data).ok()?;
+    token_metadata_extension(mint, &state)
+}
+
+/// The metadata extension of `state`, if it describes `mint`
+fn token_metadata_extension(
+    mint: &Pubkey,
+    state: &StateWithExtensions<Mint>,
+) -> Option<TokenMetadata> {
+    let metadata = state
+        .get_variable_len_extension::<Token2022Metadata>()
+        .ok()?;
+    if metadata.mint != *mint {
+        return None;
+    }
+    let update_authority = Option::<Pubkey>::from(metadata.update_authority);
+
+    // Token-2022 metadata has no creators or collection to verify; without an
+    // update authority it can never change again
+    Some(TokenMetadata {
+        update_authority,
+        is_mutable: update_authority.is_some(),
+        additional_metadata: metadata.additional_metadata,
+        source: MetadataSource::Token2022,
+        ..TokenMetadata::basic(metadata.name, metadata.symbol, metadata.uri)
+    })
+}
+
+fn from_metaplex(metadata: Metadata) -> TokenMetadata {
+    let creators = metadata
+        .creators
+        .unwrap_or_default()
+        .into_iter()
+        .map(|creator| MetadataCreator {
+            address: Pubkey::new_from_array(creator.address.to_bytes()),
+            verified: creator.verified,
+            share: creator.share,
+        })
+        .collect();
+
+    let collection = metadata.collection.map(|collection| MetadataCollection {
+        key: Pubkey::new_from_array(collection.key.to_bytes()),
+        verified: collection.verified,
+    });
+
+    let mut token_metadata = TokenMetadata {
+        update_authority: Some(Pubkey::new_from_array(metadata.update_authority.to_bytes())),
+        is_mutable: metadata.is_mutable,
+        primary_sale_happened: metadata.primary_sale_happened,
+        seller_fee_basis_points: metadata.seller_fee_basis_points,
+        creators,
+        collection,
+        token_standard: metadata
+            .token_standard
+            .map(|standard| format!("{:?}", standard)),
+        ..TokenMetadata::basic(
+            trim(&metadata.name),
+            trim(&metadata.symbol),
+            trim(&metadata.uri),
+        )
+    };
+
+    // Anyone can write metadata for a mint; verification takes a signature from
+    // the creator or the collection authority
+    token_metadata.is_verified = token_metadata.has_verified_collection()
+        || token_metadata.verified_creators().next().is_some();
+
+    token_metadata
+}
+
+/// Parse name, symbol and uri by hand.
+///
+/// Layout (simplified): key (1) | update_authority (32) | mint (32) |
+/// name (4 + 32) | symbol (4 + 10) | uri (4 + 200). Truncated data yields empty strings.
+fn decode_display_fields(data: &[u8]) -> TokenMetadata {
+    let mut offset = 1 + 32 + 32; // Skip key, update_authority, mint
+
+    let name = read_string(data, &mut offset);
+    let symbol = read_string(data, &mut offset);
+    let uri = read_string(data, &mut offset);
+
+    TokenMetadata {
+        update_authority: data
+            .get(1..33)
+            .and_then(|bytes| Pubkey::try_from(bytes).ok()),
+        ..TokenMetadata::basic(trim(&name), trim(&symbol), trim(&uri))
+    }
+}
+
+fn trim(value: &str) -> String {
+    value.trim_matches('\0').to_string()
+}
+
+fn read_string(data: &[u8], offset: &mut usize) -> String {
+    if *offset + 4 > data.len() {
+        return String::new();
+    }
+
+    let len = u32::from_le_bytes([
+        data[*offset],
+        data[*offset + 1],
+        data[*offset + 2],
+        data[*offset + 3],
+    ]) as usize;
+
+    *offset += 4;
+
+    if *offset + len > data.len() {
+        return String::new();
+    }
+
+    let string = String::from_utf8_lossy(&data[*offset..*offset + len]).to_string();
+    *offset += len;
+
+    string
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use spl_token_2022::extension::{
+        BaseStateWithExtensionsMut, ExtensionType, StateWithExtensionsMut,
+    };
+
+    /// Borsh-encoded `MetadataV1` account, padded with zeros like on-chain accounts
+    fn metadata_account(
+        update_authority: Pubkey,
+        creators: &[(Pubkey, bool)],
+        collection: Option<(Pubkey, bool)>,
+    ) -> Vec<u8> {
+        metadata_account_for(Pubkey::new_unique(), update_authority, creators, collection)
+    }
+
+    fn metadata_account_for(
+        mint: Pubkey,
+        update_authority: Pubkey,
+        creators: &[(Pubkey, bool)],
+        collection: Option<(Pubkey, bool)>,
+    ) -> Vec<u8> {
+        let string = |data: &mut Vec<u8>, value: &str| {
+            data.extend_from_slice(&(value.len() as u32).to_le_bytes());
+            data.extend_from_slice(value.as_bytes());
+        };
+
+        let mut data = vec![4]; // Key::MetadataV1
+        data.extend_from_slice(update_authority.as_ref());
+        data.extend_from_slice(mint.as_ref());
+        string(&mut data, "Token\0\0\0");
+        string(&mut data, "TKN");
+        string(&mut data, "https://example.com/token.json");
+        data.extend_from_slice(&500u16.to_le_bytes());
+
+        data.push(1);
+        data.extend_from_slice(&(creators.len() as u32).to_le_bytes());
+        for (address, verified) in creators {
+            data.extend_from_slice(address.as_ref());
+            data.push(*verified as u8);
+            data.push((100 / creators.len()) as u8);
+        }
+
+        data.push(0); // primary_sale_happened
+        data.push(1); // is_mutable
+        data.push(0); // edition_nonce: None
+        data.extend_from_slice(&[1, 2]); // token_standard: Some(Fungible)
+        match collection {
+            Some((key, verified)) => {
+                data.push(1);
+                data.push(verified as u8);
+                data.extend_from_slice(key.as_ref());
+            }
+            None => data.push(0),
+        }
+
+        data.resize(679, 0);
+        data
+    }
+
+    #[test]
+    fn test_full_metadata_is_decoded() {
+        let update_authority = Pubkey::new_unique();
+        let creator = Pubkey::new_unique();
+
+        let metadata = decode_metadata(&metadata_account(
+            update_authority,
+            &[(creator, false)],
+            None,
+        ));
+
+        assert_eq!(metadata.name, "Token");
+        assert_eq!(metadata.symbol, "TKN");
+        assert_eq!(metadata.update_authority, Some(update_authority));
+        assert!(metadata.is_mutable);
+        assert_eq!(metadata.seller_fee_basis_points, 500);
+        assert_eq!(metadata.token_standard.as_deref(), Some("Fungible"));
+        assert_eq!(metadata.creators.len(), 1);
+        assert!(!metadata.is_verified);
+    }
+
+    #[test]
+    fn test_verified_creator_or_collection_counts_as_verified() {
+        let authority = Pubkey::new_unique();
+
+        let by_creator = decode_metadata(&metadata_account(
+            authority,
+            &[(Pubkey::new_unique(), true)],
+            None,
+        ));
+        assert!(by_creator.is_verified);
+
+        let by_collection = decode_metadata(&metadata_account(
+            authority,
+            &[],
+            Some((Pubkey::new_unique(), true)),
+        ));
+        assert!(by_collection.is_verified);
+        assert!(by_collection.has_verified_collection());
+
+        let unverified_collection = decode_metadata(&metadata_account(
+            authority,
+            &[],
+            Some((Pubkey::new_unique(), false)),
+        ));
+        assert!(!unverified_collection.is_verified);
+    }
+
+    /// Token-2022 mint whose `MetadataPointer` names `pointer`, with embedded metadata
+    fn token_2022_mint(
+        address: Pubkey,
+        pointer: Pubkey,
+        update_authority: Option<Pubkey>,
+    ) -> Vec<u8> {
+        let metadata = Token2022Metadata {
+            update_authority: update_authority.try_into().unwrap(),
+            mint: address,
+            name: "Token".to_string(),
+            symbol: "TKN".to_string(),
+            uri: "https://example.com/token.json".to_string(),
+            additional_metadata: vec![("website".to_string(), "example.com".to_string())],
+        };
+
+        let space =
+            ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::MetadataPointer])
+                .unwrap()
+                + metadata.tlv_size_of().unwrap();
+        let mut data = vec![0u8; space];
+
+        let mut state = StateWithExtensionsMut::<Mint>::unpack_uninitialized(&mut data).unwrap();
+        state
+            .init_extension::<MetadataPointer>(true)
+            .unwrap()
+            .metadata_address = Some(pointer).try_into().unwrap();
+        state.base = Mint {
+            is_initialized: true,
+            ..Mint::default()
+        };
+        state.pack_base();
+        state.init_account_type().unwrap();
+        state.init_variable_len_extension(&metadata, false).unwrap();
+
+        data
+    }
+
+    #[test]
+    fn test_embedded_token_2022_metadata_is_decoded() {
+        let address = Pubkey::new_unique();
+        let update_authority = Pubkey::new_unique();
+        let data = token_2022_mint(address, address, Some(update_authority));
+        let mint = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+
+        let metadata = embedded_metadata(&address, &mint).unwrap();
+
+        assert_eq!(metadata.source, MetadataSource::Token2022);
+        assert_eq!(metadata.symbol, "TKN");
+        assert_eq!(metadata.update_authority, Some(update_authority));
+        assert!(metadata.is_mutable);
+        assert_eq!(
+            metadata.additional_metadata,
+            vec![("website".to_string(), "example.com".to_string())]
+        );
+
+        let immutable = token_2022_mint(address, address, None);
+        let mint = StateWithExtensions::<Mint>::unpack(&immutable).unwrap();
+        assert!(!embedded_metadata(&address, &mint).unwrap().is_mutable);
+    }
+
+    #[test]
+    fn test_pointer_elsewhere_is_not_embedded() {
+        let address = Pubkey::new_unique();
+        let elsewhere = Pubkey::new_unique();
+        let data = token_2022_mint(address, elsewhere, None);
+        let mint = StateWithExtensions::<Mint>::unpack(&data).unwrap();
+
+        assert_eq!(metadata_pointer(&mint), Some(elsewhere));
+        assert!(embedded_metadata(&address, &mint).is_none());
+    }
+
+    #[test]
+    fn test_pointed_metadata_of_another_mint_is_rejected() {
+        let mint = Pubkey::new_unique();
+        let other = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let account = |owner: Pubkey, data: Vec<u8>| Account {
+            lamports: 1,
+            data,
+            owner,
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        // A Metaplex account, e.g. a well-known token's, describing `other`
+        let metaplex = |mint: Pubkey| {
+            account(
+                METADATA_PROGRAM_ID,
+                metadata_account_for(mint, authority, &[(authority, true)], None),
+            )
+        };
+        assert!(decode_pointed_metadata(&mint, &metaplex(other)).is_none());
+        assert!(
+            decode_pointed_metadata(&mint, &metaplex(mint))
+                .unwrap()
+                .is_verified
+        );
+
+        // Another Token-2022 mint whose embedded metadata is its own
+        let foreign = account(
+            spl_token_2022::id(),
+            token_2022_mint(other, other, Some(authority)),
+        );
+        assert!(decode_pointed_metadata(&mint, &foreign).is_none());
+        assert!(decode_pointed_metadata(&other, &foreign).is_some());
+    }
+
+    #[test]
+    fn test_truncated_account_falls_back_to_display_fields() {
+        let update_authority = Pubkey::new_unique();
+        let mut data = metadata_account(update_authority, &[], None);
+        data.truncate(120);
+
+        let metadata = decode_metadata(&data);
+
+        assert_eq!(metadata.name, "Token");
+        assert_eq!(metadata.update_authority, Some(update_authority));
+        assert!(metadata.creators.is_empty());
+    }
+}
@@ -0,0 +1,220 @@
+use solana_client::client_error::{reqwest::StatusCode, ClientError, ClientErrorKind};
+use solana_client::rpc_custom_error::{
+    JSON_RPC_SERVER_ERROR_BLOCK_STATUS_NOT_AVAILABLE_YET,
+    JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED, JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+};
+use solana_client::rpc_request::RpcError;
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::time::Duration;
+
+/// Timeout, deadline and retry settings applied to every RPC call
+#[derive(Debug, Clone)]
+pub struct RpcPolicy {
+    /// Timeout for a single RPC request
+    pub request_timeout: Duration,
+    /// Total time budget for one analysis (`None` = no deadline)
+    pub deadline: Option<Duration>,
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry; doubled on every further retry
+    pub backoff_base: Duration,
+    /// Upper bound for a single backoff delay
+    pub backoff_cap: Duration,
+    /// Share of each delay that is randomised: 0.0 = fixed, 1.0 = full jitter
+    pub jitter: f64,
+    /// Which error classes are retried
+    pub retry_on: RetryOn,
+}
+
+/// Error classes that [`RpcPolicy`] retries
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// HTTP 429 / provider rate limits
+    pub rate_limited: bool,
+    /// HTTP 5xx, connection errors, unhealthy or lagging nodes
+    pub server_error: bool,
+    /// Request timeouts
+    pub timeout: bool,
+}
+
+/// Coarse classification of an RPC failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorClass {
+    RateLimited,
+    ServerError,
+    Timeout,
+    Other,
+}
+
+impl Default for RpcPolicy {
+    fn default() -> Self {
+        Self {
+            request_timeout: Duration::from_secs(10),
+            deadline: Some(Duration::from_secs(120)),
+            max_retries: 3,
+            backoff_base: Duration::from_millis(500),
+            backoff_cap: Duration::from_secs(8),
+            jitter: 0.5,
+            retry_on: RetryOn::default(),
+        }
+    }
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self {
+            rate_limited: true,
+            server_error: true,
+            timeout: true,
+        }
+    }
+}
+
+impl RpcPolicy {
+    /// Default policy with a custom per-request timeout
+    pub fn with_timeout(request_timeout: Duration) -> Self {
+        Self {
+            request_timeout,
+            ..Self::default()
+        }
+    }
+
+    /// Whether a failure of this class should be retried
+    pub fn should_retry(&self, class: ErrorClass) -> bool {
+        match class {
+            ErrorClass::RateLimited => self.retry_on.rate_limited,
+            ErrorClass::ServerError => self.retry_on.server_error,
+            ErrorClass::Timeout => self.retry_on.timeout,
+            ErrorClass::Other => false,
+        }
+    }
+
+    /// Delay before retry number `attempt` (0-based): exponential, capped, with jitter
+    pub fn backoff_delay(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        let delay = self
+            .backoff_base
+            .saturating_mul(factor)
+            .min(self.backoff_cap);
+
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        if jitter == 0.0 {
+            return delay;
+        }
+
+        delay.mul_f64(1.0 - jitter * random_unit())
+    }
+}
+
+impl ErrorClass {
+    pub fn of(err: &ClientError) -> Self {
+        match err.kind() {
+            ClientErrorKind::Reqwest(e) => {
+                if e.is_timeout() {
+                    ErrorClass::Timeout
+                } else if e.status() == Some(StatusCode::TOO_MANY_REQUESTS) {
+                    ErrorClass::RateLimited
+                } else if e.status().is_some_and(|s| s.is_server_error()) || e.is_connect() {
+                    ErrorClass::ServerError
+                } else {
+                    ErrorClass::Other
+                }
+            }
+            ClientErrorKind::Io(e) if e.kind() == std::io::ErrorKind::TimedOut => {
+                ErrorClass::Timeout
+            }
+            ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => match *code {
+                // Some providers report rate limits as JSON-RPC errors instead of HTTP 429
+                429 | -32429 => ErrorClass::RateLimited,
+                JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+                | JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED
+                | JSON_RPC_SERVER_ERROR_BLOCK_STATUS_NOT_AVAILABLE_YET => ErrorClass::ServerError,
+                _ => ErrorClass::Other,
+            },
+            _ => ErrorClass::Other,
+        }
+    }
+}
+
+/// Uniform value in `[0, 1)`; `RandomState` is freshly seeded on every call,
+/// which is plenty for spreading out retries.
+fn random_unit() -> f64 {
+    let bits = RandomState::new().build_hasher().finish();
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_client::rpc_request::RpcResponseErrorData;
+
+    fn fixed_policy() -> RpcPolicy {
+        RpcPolicy {
+            jitter: 0.0,
+            ..RpcPolicy::default()
+        }
+    }
+
+    #[test]
+    fn test_backoff_doubles_until_cap() {
+        let policy = fixed_policy();
+
+        assert_eq!(policy.backoff_delay(0), Duration::from_millis(500));
+        assert_eq!(policy.backoff_delay(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff_delay(3), Duration::from_secs(4));
+        assert_eq!(policy.backoff_delay(10), Duration::from_secs(8));
+        assert_eq!(policy.backoff_delay(u32::MAX), Duration::from_secs(8));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RpcPolicy {
+            jitter: 1.0,
+            ..RpcPolicy::default()
+        };
+
+        for _ in 0..100 {
+            assert!(policy.backoff_delay(2) <= Duration::from_secs(2));
+        }
+    }
+
+    #[test]
+    fn test_rpc_error_classification() {
+        let unhealthy: ClientError = ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            code: JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+            message: "Node is behind".to_string(),
+            data: RpcResponseErrorData::Empty,
+        })
+        .into();
+        assert_eq!(ErrorClass::of(&unhealthy), ErrorClass::ServerError);
+
+        let invalid: ClientError = ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            code: -32602,
+            message: "Invalid params".to_string(),
+            data: RpcResponseErrorData::Empty,
+        })
+        .into();
+        assert_eq!(ErrorClass::of(&invalid), ErrorClass::Other);
+
+        let timeout: ClientError =
+            ClientErrorKind::Io(std::io::Error::from(std::io::ErrorKind::TimedOut)).into();
+        assert_eq!(ErrorClass::of(&timeout), ErrorClass::Timeout);
+    }
+
+    #[test]
+    fn test_retry_on_respects_switches() {
+        let policy = RpcPolicy {
+            retry_on: RetryOn {
+                rate_limited: true,
+                server_error: false,
+                timeout: true,
+            },
+            ..RpcPolicy::default()
+        };
+
+        assert!(policy.should_retry(ErrorClass::RateLimited));
+        assert!(!policy.should_retry(ErrorClass::ServerError));
+        assert!(!policy.should_retry(ErrorClass::Other));
+    }
+}
@@ -0,0 +1,131 @@
+use crate::layout::{i64_at, u32_at};
+use solana_sdk::pubkey::Pubkey;
+
+/// Pyth SOL/USD price feed account (Pyth Solana Receiver `PriceUpdateV2`)
+pub const PYTH_SOL_USD_PRICE_ACCOUNT: Pubkey =
+    solana_sdk::pubkey!("7UVimffxr9ow1uXYxsr4LHAcV58mLzhmwaeKvJ1pjLiE");
+
+pub const WSOL_MINT: Pubkey = solana_sdk::pubkey!("So11111111111111111111111111111111111111112");
+
+/// Quote tokens whose base unit price is pinned to the dollar
+const USD_STABLECOINS: &[Pubkey] = &[
+    solana_sdk::pubkey!("EPjFWdd5AufqSSqeM2qN1xzybapC8G4wEGGkZwyTDt1v"), // USDC
+    solana_sdk::pubkey!("Es9vMFrzaCERmJfrF4H2FYD4KCoNkY11McCe8BenwNYB"), // USDT
+];
+
+/// Anchor discriminator of the Pyth receiver's `PriceUpdateV2` account
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+/// Magic number opening a legacy Pyth oracle account
+const LEGACY_PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+/// Legacy account type of a price account, and aggregate status "trading"
+const LEGACY_PRICE_ACCOUNT: u32 = 3;
+const LEGACY_STATUS_TRADING: u32 = 1;
+
+/// Prices published longer ago than this aren't used
+const MAX_PRICE_AGE_SECS: i64 = 600;
+
+/// A price from a Pyth account: `price * 10^exponent` as of `publish_time`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// The price, unless it isn't positive or is older than `MAX_PRICE_AGE_SECS` at `now`
+    pub fn value(&self, now: i64) -> Option<f64> {
+        if self.price <= 0 || now - self.publish_time > MAX_PRICE_AGE_SECS {
+            return None;
+        }
+        Some(self.price as f64 * 10f64.powi(self.exponent))
+    }
+}
+
+/// Decode a Pyth price: a receiver `PriceUpdateV2` account, or a legacy price
+/// account whose aggregate is trading
+pub fn decode_pyth(data: &[u8]) -> Option<OraclePrice> {
+    if data.get(..8)? == PRICE_UPDATE_V2_DISCRIMINATOR {
+        // write_authority, then `VerificationLevel`: Partial { num_signatures } or Full
+        let message = match data.get(40)? {
+            0 => 42,
+            1 => 41,
+            _ => return None,
+        };
+        // feed_id, price, conf, exponent, publish_time
+        return Some(OraclePrice {
+            price: i64_at(data, message + 32)?,
+            exponent: u32_at(data, message + 48)? as i32,
+            publish_time: i64_at(data, message + 52)?,
+        });
+    }
+
+    if u32_at(data, 0)? == LEGACY_PYTH_MAGIC
+        && u32_at(data, 8)? == LEGACY_PRICE_ACCOUNT
+        && u32_at(data, 224)? == LEGACY_STATUS_TRADING
+    {
+        return Some(OraclePrice {
+            price: i64_at(data, 208)?,
+            exponent: u32_at(data, 20)? as i32,
+            publish_time: i64_at(data, 96)?,
+        });
+    }
+
+    None
+}
+
+/// USD value of one whole `quote_mint` token: 1 for dollar stablecoins, the
+/// SOL/USD price for wrapped SOL
+pub fn quote_usd(quote_mint: &Pubkey, sol_usd: Option<f64>) -> Option<f64> {
+    if USD_STABLECOINS.contains(quote_mint) {
+        Some(1.0)
+    } else if *quote_mint == WSOL_MINT {
+        sol_usd
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::put;
+
+    #[test]
+    fn test_decodes_pyth_prices() {
+        // Fully verified update: $142.50 with exponent -8
+        let mut update = vec![0u8; 134];
+        put(&mut update, 0, &PRICE_UPDATE_V2_DISCRIMINATOR);
+        update[40] = 1;
+        put(&mut update, 73, &14_250_000_000i64.to_le_bytes());
+        put(&mut update, 89, &(-8i32).to_le_bytes());
+        put(&mut update, 93, &1_700_000_000i64.to_le_bytes());
+
+        let price = decode_pyth(&update).unwrap();
+        assert!((price.value(1_700_000_060).unwrap() - 142.5).abs() < 1e-9);
+        assert_eq!(price.value(1_700_000_000 + MAX_PRICE_AGE_SECS + 1), None);
+
+        let mut legacy = vec![0u8; 240];
+        put(&mut legacy, 0, &LEGACY_PYTH_MAGIC.to_le_bytes());
+        put(&mut legacy, 8, &LEGACY_PRICE_ACCOUNT.to_le_bytes());
+        put(&mut legacy, 20, &(-6i32).to_le_bytes());
+        put(&mut legacy, 96, &1_700_000_000i64.to_le_bytes());
+        put(&mut legacy, 208, &150_000_000i64.to_le_bytes());
+        put(&mut legacy, 224, &LEGACY_STATUS_TRADING.to_le_bytes());
+        let price = decode_pyth(&legacy).unwrap();
+        assert!((price.value(1_700_000_000).unwrap() - 150.0).abs() < 1e-9);
+
+        // Aggregate not trading
+        put(&mut legacy, 224, &0u32.to_le_bytes());
+        assert_eq!(decode_pyth(&legacy), None);
+    }
+
+    #[test]
+    fn test_quote_prices() {
+        assert_eq!(quote_usd(&USD_STABLECOINS[1], None), Some(1.0));
+        assert_eq!(quote_usd(&WSOL_MINT, Some(150.0)), Some(150.0));
+        assert_eq!(quote_usd(&WSOL_MINT, None), None);
+        assert_eq!(quote_usd(&Pubkey::new_unique(), Some(150.0)), None);
+    }
+}
@@ -0,0 +1,164 @@
+use crate::metadata::{decode_metadata, embedded_metadata};
+use solana_program::program_option::COption;
+use solana_sdk::account::Account;
+use solana_sdk::pubkey::Pubkey;
+use spl_risk_core::error::RiskError;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::Mint;
+use std::fmt::Display;
+
+/// The parts of the mint and metadata accounts that drive the score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CriticalFields {
+    pub owner: Pubkey,
+    pub supply: u64,
+    pub decimals: u8,
+    pub mint_authority: Option<Pubkey>,
+    pub freeze_authority: Option<Pubkey>,
+    /// `(name, symbol, uri)`; `None` when neither embedded Token-2022 metadata
+    /// nor the Metaplex account exists
+    pub metadata: Option<(String, String, String)>,
+}
+
+impl CriticalFields {
+    pub fn decode(
+        address: &Pubkey,
+        mint: &Account,
+        metadata: Option<&Account>,
+    ) -> Result<Self, RiskError> {
+        let state = StateWithExtensions::<Mint>::unpack(&mint.data)
+            .map_err(|e| RiskError::ParseError(e.to_string()))?;
+
+        let authority = |key: COption<Pubkey>| Option::from(key);
+
+        let metadata = embedded_metadata(address, &state)
+            .or_else(|| metadata.map(|account| decode_metadata(&account.data)))
+            .map(|metadata| (metadata.name, metadata.symbol, metadata.uri));
+
+        Ok(Self {
+            owner: mint.owner,
+            supply: state.base.supply,
+            decimals: state.base.decimals,
+            mint_authority: authority(state.base.mint_authority),
+            freeze_authority: authority(state.base.freeze_authority),
+            metadata,
+        })
+    }
+}
+
+/// Compare every read against the first one.
+///
+/// Each entry is `(endpoint url, fields)`; the result lists one line per differing
+/// field and endpoint, e.g. `supply: 1000 at A vs 2000 at B`.
+pub fn compare(reads: &[(String, CriticalFields)]) -> Vec<String> {
+    let Some(((reference_url, reference), others)) = reads.split_first() else {
+        return Vec::new();
+    };
+
+    let mut disagreements = Vec::new();
+    for (url, fields) in others {
+        let mut check = |field: &str, a: String, b: String| {
+            if a != b {
+                disagreements.push(format!(
+                    "{}: {} at {} vs {} at {}",
+                    field, a, reference_url, b, url
+                ));
+            }
+        };
+
+        check(
+            "owner program",
+            reference.owner.to_string(),
+            fields.owner.to_string(),
+        );
+        check(
+            "supply",
+            reference.supply.to_string(),
+            fields.supply.to_string(),
+        );
+        check(
+            "decimals",
+            reference.decimals.to_string(),
+            fields.decimals.to_string(),
+        );
+        check(
+            "mint authority",
+            or_none(&reference.mint_authority),
+            or_none(&fields.mint_authority),
+        );
+        check(
+            "freeze authority",
+            or_none(&reference.freeze_authority),
+            or_none(&fields.freeze_authority),
+        );
+
+        match (&reference.metadata, &fields.metadata) {
+            (Some(a), Some(b)) => {
+                check("metadata name", a.0.clone(), b.0.clone());
+                check("metadata symbol", a.1.clone(), b.1.clone());
+                check("metadata uri", a.2.clone(), b.2.clone());
+            }
+            (a, b) => check(
+                "metadata account",
+                presence(a.is_some()),
+                presence(b.is_some()),
+            ),
+        }
+    }
+
+    disagreements
+}
+
+fn or_none<T: Display>(value: &Option<T>) -> String {
+    value
+        .as_ref()
+        .map(|v| v.to_string())
+        .unwrap_or_else(|| "none".to_string())
+}
+
+fn presence(exists: bool) -> String {
+    if exists { "present" } else { "missing" }.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fields() -> CriticalFields {
+        CriticalFields {
+            owner: Pubkey::new_unique(),
+            supply: 1_000,
+            decimals: 6,
+            mint_authority: None,
+            freeze_authority: None,
+            metadata: Some(("Token".into(), "TKN".into(), "https://x".into())),
+        }
+    }
+
+    #[test]
+    fn test_identical_reads_agree() {
+        let fields = fields();
+        let reads = [("a".to_string(), fields.clone()), ("b".to_string(), fields)];
+
+        assert!(compare(&reads).is_empty());
+    }
+
+    #[test]
+    fn test_differing_fields_are_reported() {
+        let reference = fields();
+        let mut other = reference.clone();
+        other.supply = 2_000;
+        other.mint_authority = Some(Pubkey::new_unique());
+        other.metadata = None;
+
+        let disagreements = compare(&[("a".to_string(), reference), ("b".to_string(), other)]);
+
+        assert_eq!(disagreements.len(), 3);
+        assert_eq!(disagreements[0], "supply: 1000 at a vs 2000 at b");
+        assert!(disagreements[1].starts_with("mint authority: none at a vs "));
+        assert_eq!(
+            disagreements[2],
+            "metadata account: present at a vs missing at b"
+        );
+    }
+}
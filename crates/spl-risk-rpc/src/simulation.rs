@@ -0,0 +1,116 @@
+use solana_sdk::account::Account;
+use solana_sdk::transaction::TransactionError;
+use spl_risk_core::model::token::SimulationOutcome;
+use spl_token_2022::extension::transfer_fee::TransferFeeConfig;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::{Account as TokenAccount, Mint};
+
+/// Program log lines kept from a failed simulation, counted from the end
+const MAX_LOGS: usize = 20;
+
+/// Position of the transfer in the simulated transaction, after the idempotent
+/// creation of the recipient's associated token account
+pub const TRANSFER_INSTRUCTION: u8 = 1;
+
+/// Largest fee the mint's transfer fees take from `amount`. Both the older and the
+/// newer fee are considered, since either may apply in the current epoch.
+pub fn declared_fee(mint: &StateWithExtensions<Mint>, amount: u64) -> u64 {
+    let Ok(config) = mint.get_extension::<TransferFeeConfig>() else {
+        return 0;
+    };
+
+    [&config.older_transfer_fee, &config.newer_transfer_fee]
+        .iter()
+        .filter_map(|fee| fee.calculate_fee(amount))
+        .max()
+        .unwrap_or(0)
+}
+
+/// What a simulated transfer says about the token, from the simulation's error,
+/// logs and the destination token account after it ran.
+///
+/// `None` when the transaction failed before its instructions ran (fee payer
+/// without SOL, missing account, ...) or in the account creation before the
+/// transfer, which says nothing about the token.
+pub fn outcome(
+    error: Option<TransactionError>,
+    logs: Vec<String>,
+    destination: Option<&Account>,
+) -> Option<SimulationOutcome> {
+    match error {
+        Some(error @ TransactionError::InstructionError(TRANSFER_INSTRUCTION, _)) => {
+            let skip = logs.len().saturating_sub(MAX_LOGS);
+            Some(SimulationOutcome::Failed {
+                error: error.to_string(),
+                logs: logs.into_iter().skip(skip).collect(),
+            })
+        }
+        Some(_) => None,
+        None => {
+            let account = StateWithExtensions::<TokenAccount>::unpack(&destination?.data).ok()?;
+            Some(SimulationOutcome::Transferred {
+                received: account.base.amount,
+            })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::program_pack::Pack;
+    use solana_sdk::instruction::InstructionError;
+    use solana_sdk::pubkey::Pubkey;
+
+    #[test]
+    fn test_outcome_of_simulation() {
+        let logs: Vec<String> = (0..30).map(|line| format!("log {}", line)).collect();
+        let frozen = TransactionError::InstructionError(1, InstructionError::Custom(17));
+        match outcome(Some(frozen), logs, None) {
+            Some(SimulationOutcome::Failed { error, logs }) => {
+                assert!(error.contains("custom program error: 0x11"));
+                assert_eq!(logs.len(), MAX_LOGS);
+                assert_eq!(logs.last().map(String::as_str), Some("log 29"));
+            }
+            other => panic!("unexpected outcome {:?}", other),
+        }
+
+        // The recipient's account couldn't be created: the transfer never ran
+        let no_account = TransactionError::InstructionError(0, InstructionError::Custom(1));
+        assert_eq!(outcome(Some(no_account), vec!["log".into()], None), None);
+
+        // The fee payer couldn't pay: nothing learned about the token
+        assert_eq!(
+            outcome(
+                Some(TransactionError::InsufficientFundsForFee),
+                Vec::new(),
+                None
+            ),
+            None
+        );
+
+        let mut data = vec![0; TokenAccount::LEN];
+        TokenAccount::pack(
+            TokenAccount {
+                mint: Pubkey::new_unique(),
+                owner: Pubkey::new_unique(),
+                amount: 990,
+                state: spl_token_2022::state::AccountState::Initialized,
+                ..TokenAccount::default()
+            },
+            &mut data,
+        )
+        .unwrap();
+        let destination = Account {
+            lamports: 2_039_280,
+            data,
+            owner: spl_token_2022::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        assert_eq!(
+            outcome(None, Vec::new(), Some(&destination)),
+            Some(SimulationOutcome::Transferred { received: 990 })
+        );
+    }
+}
@@ -1,44 +1,295 @@
+use crate::authority;
 use crate::cache::Cache;
+use crate::creator;
+use crate::curve;
+use crate::extensions;
+use crate::history;
+use crate::labels;
+#[cfg(feature = "lp-analysis")]
+use crate::liquidity::{self, PoolLayout, PoolProgram, PositionHolder, PositionProgram};
+#[cfg(feature = "lp-analysis")]
+use crate::locker::{self, LockTerms};
+use crate::metadata::{
+    decode_metadata, decode_pointed_metadata, embedded_metadata, metadata_pda, metadata_pointer,
+    METADATA_PROGRAM_ID,
+};
+use crate::policy::{ErrorClass, RpcPolicy};
+use crate::pool::{redact_urls, EndpointStats, RpcPool};
+#[cfg(feature = "lp-analysis")]
+use crate::price;
+use crate::quorum::{self, CriticalFields};
+use crate::simulation;
 use anyhow::Result;
 use async_trait::async_trait;
+use futures::stream::{self, StreamExt};
+use solana_account_decoder::{UiAccountData, UiAccountEncoding, UiDataSliceConfig};
+use solana_client::client_error::ClientError;
 use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_client::GetConfirmedSignaturesForAddress2Config;
+use solana_client::rpc_config::{
+    RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionAccountsConfig,
+    RpcSimulateTransactionConfig, RpcTransactionConfig,
+};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_client::rpc_request::TokenAccountsFilter;
+use solana_client::rpc_response::RpcConfirmedTransactionStatusWithSignature;
 use solana_commitment_config::CommitmentConfig;
 use solana_program::program_option::COption;
+use solana_sdk::account::Account;
+use solana_sdk::message::{Message, VersionedMessage};
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signature};
+use solana_sdk::signer::Signer;
+use solana_sdk::transaction::{TransactionError, VersionedTransaction};
+use solana_transaction_status::{EncodedConfirmedTransactionWithStatusMeta, UiTransactionEncoding};
+use spl_associated_token_account_interface::address::get_associated_token_address_with_program_id;
+use spl_associated_token_account_interface::instruction::create_associated_token_account_idempotent;
 use spl_risk_core::error::RiskError;
+use spl_risk_core::known_tokens::KnownTokens;
+use spl_risk_core::labels::{HolderKind, HolderLabel, LabelRegistry};
+use spl_risk_core::model::token::FetchStatus;
+use spl_risk_core::model::token::HolderCounts;
+use spl_risk_core::model::token::QuorumCheck;
+use spl_risk_core::model::token::TokenCreator;
 use spl_risk_core::model::token::TokenData;
 use spl_risk_core::model::token::TokenHolder;
 use spl_risk_core::model::token::TokenMetadata;
+use spl_risk_core::model::token::{
+    AuthorityAction, AuthorityEvent, AuthorityHistory, AuthorityKind, BondingCurve, EarlyBuyer,
+    LaunchActivity, TransferSimulation, LAUNCH_WINDOW_SLOTS,
+};
+#[cfg(feature = "lp-analysis")]
+use spl_risk_core::model::token::{LiquidityPool, LpLock, LpToken, PositionOwnership};
 use spl_risk_core::provider::TokenDataProvider;
-use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::extension::{AccountType, StateWithExtensions};
+use spl_token_2022::offchain::{
+    create_transfer_checked_instruction_with_extra_metas, AccountFetchError,
+};
 use spl_token_2022::state::{Account as TokenAccount, Mint};
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::future::Future;
 use std::str::FromStr;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
+
+/// Most accounts a single `getMultipleAccounts` request may ask for
+const MAX_MULTIPLE_ACCOUNTS: usize = 100;
+/// `getTokenLargestAccounts` never returns more accounts than this
+const LARGEST_ACCOUNTS_LIMIT: usize = 20;
+
+/// `getSignaturesForAddress` returns at most this many signatures per page
+const SIGNATURE_PAGE_LIMIT: usize = 1000;
+/// Default budget for walking back to an address' first transaction
+const DEFAULT_HISTORY_PAGES: usize = 10;
+const DEFAULT_HISTORY_TIME: Duration = Duration::from_secs(15);
+/// Mint transactions decoded for the authority timeline
+const MAX_HISTORY_TRANSACTIONS: usize = 200;
+/// Wallets that received an authority whose first transaction is looked up
+const MAX_AUTHORITY_WALLETS: usize = 5;
+
+pub(crate) const LEGACY_TOKEN_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+pub(crate) const SYSTEM_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("11111111111111111111111111111111");
+
+/// Launch transactions decoded at most
+const MAX_LAUNCH_TRANSACTIONS: usize = 100;
+/// Launch buyers whose current balance is looked up
+const MAX_LAUNCH_BALANCES: usize = 30;
+
+/// Top user holders considered as the sender of the simulated transfer
+const MAX_SIMULATION_CANDIDATES: usize = 5;
+/// SOL a holder needs for the simulated transaction: fee plus the new account's rent
+const MIN_SIMULATION_LAMPORTS: u64 = 5_000_000;
+
+/// Largest pools per mint kept for liquidity analysis
+#[cfg(feature = "lp-analysis")]
+const MAX_LIQUIDITY_POOLS: usize = 10;
+/// Largest positions of a concentrated-liquidity pool whose owners are resolved
+#[cfg(feature = "lp-analysis")]
+const MAX_OWNED_POSITIONS: usize = 10;
+
+/// Oldest transactions of a wallet searched for the SOL transfer that funded it
+const FUNDING_LOOKUPS: usize = 3;
+
+/// Largest holders whose owner accounts are fetched to spot pools and other programs
+const MAX_LABELED_HOLDERS: usize = 20;
+
+/// Token account layout: mint (32) | owner (32) | amount (8) | ...; 165 bytes without extensions
+const TOKEN_ACCOUNT_LEN: usize = 165;
+const TOKEN_ACCOUNT_OWNER_OFFSET: usize = 32;
 
 pub struct SolanaRpcClient {
-    client: Arc<RpcClient>,
+    pool: Arc<RpcPool>,
+    policy: RpcPolicy,
+    // End of the analysis' time budget; set on the per-analysis copy of the client
+    deadline: Option<Instant>,
+    // Endpoints that must serve the critical accounts (0 = quorum mode off)
+    quorum: usize,
+    // Scan every token account of the mint instead of only the largest ones
+    deep_holders: bool,
+    // Wallet age lookups in flight at once
+    concurrency: usize,
+    // Decode the mint's transactions into an authority timeline
+    authority_history: bool,
+    // Read the mint's first slots for snipers and bundled buys
+    launch_analysis: bool,
+    // Simulate a transfer out of a top holder's account
+    transfer_simulation: bool,
+    // Budget per signature-history walk: pages, and time (`None` = no limit)
+    history_pages: usize,
+    history_time: Option<Duration>,
+    // Known non-user holders
+    labels: Arc<LabelRegistry>,
+    // Tokens whose name and symbol scams imitate
+    known_tokens: Arc<KnownTokens>,
     // Caches with TTL
     token_cache: Cache<TokenData>,
     metadata_cache: Cache<TokenMetadata>,
-    wallet_age_cache: Cache<u64>,
+    wallet_age_cache: Cache<WalletAge>,
+}
+
+/// Mint and freeze authority classifications, and the endpoint that served them
+type AuthorityKinds = (
+    (Option<AuthorityKind>, Option<AuthorityKind>),
+    Option<String>,
+);
+
+/// Signatures of an address, newest first, as far back as a history walk reached
+struct SignatureHistory {
+    signatures: Vec<RpcConfirmedTransactionStatusWithSignature>,
+    /// The walk reached the address' first transaction instead of running out of budget
+    complete: bool,
+    /// Endpoint that served the oldest page
+    endpoint: String,
+}
+
+/// Wallet age in days (a lower bound unless `complete`) and who funded the wallet
+#[derive(Debug, Clone, Copy)]
+struct WalletAge {
+    days: u64,
+    complete: bool,
+    /// Sender of the wallet's first incoming SOL transfer; only looked up when
+    /// the history walk reached the wallet's first transaction
+    funder: Option<Pubkey>,
 }
 
 impl SolanaRpcClient {
-    pub fn new(url: &str, _timeout: Duration) -> Result<Self> {
-        let client = Arc::new(RpcClient::new_with_commitment(
-            url.to_string(),
-            CommitmentConfig::confirmed(),
-        ));
+    pub fn new(url: &str, timeout: Duration) -> Result<Self> {
+        Self::with_policy(url, RpcPolicy::with_timeout(timeout))
+    }
+
+    pub fn with_policy(url: &str, policy: RpcPolicy) -> Result<Self> {
+        Self::with_endpoints(&[url.to_string()], policy)
+    }
+
+    /// Client backed by several endpoints; calls go to the healthiest one
+    /// and fail over to the next on rate limits, server errors and timeouts.
+    pub fn with_endpoints(urls: &[String], policy: RpcPolicy) -> Result<Self> {
+        if urls.is_empty() {
+            return Err(RiskError::ConfigError("No RPC endpoints configured".to_string()).into());
+        }
 
         Ok(Self {
-            client,
+            pool: Arc::new(RpcPool::new(urls, policy.request_timeout)),
+            policy,
+            deadline: None,
+            quorum: 0,
+            deep_holders: false,
+            concurrency: 4,
+            authority_history: false,
+            launch_analysis: false,
+            transfer_simulation: false,
+            history_pages: DEFAULT_HISTORY_PAGES,
+            history_time: Some(DEFAULT_HISTORY_TIME),
+            labels: Arc::new(LabelRegistry::builtin()),
+            known_tokens: Arc::new(KnownTokens::builtin()),
             token_cache: Cache::new(Duration::from_secs(300), 1000),
             metadata_cache: Cache::new(Duration::from_secs(300), 1000),
             wallet_age_cache: Cache::new(Duration::from_secs(600), 5000),
         })
     }
 
+    /// Cross-check the mint and metadata accounts across `size` endpoints.
+    ///
+    /// The best-ranked endpoints are read at the same minimum context slot and any
+    /// disagreement ends up in [`FetchStatus::quorum`]. Sizes below 2 turn the check
+    /// off; sizes above the number of endpoints are rejected.
+    pub fn with_quorum(mut self, size: usize) -> Result<Self> {
+        let endpoints = self.pool.endpoint_count();
+        if size > endpoints {
+            return Err(RiskError::ConfigError(format!(
+                "quorum of {} needs at least {} RPC endpoints, {} configured",
+                size, size, endpoints
+            ))
+            .into());
+        }
+        self.quorum = if size >= 2 { size } else { 0 };
+        Ok(self)
+    }
+
+    /// Count and load every holder with `getProgramAccounts` instead of only the 20
+    /// largest accounts. Exact, but heavy for widely held tokens and not offered by
+    /// every RPC provider; a failed scan falls back to the largest accounts.
+    pub fn with_deep_holders(mut self, enabled: bool) -> Self {
+        self.deep_holders = enabled;
+        self
+    }
+
+    /// Number of holder wallet ages looked up concurrently (at least 1, default 4)
+    pub fn with_concurrency(mut self, limit: usize) -> Self {
+        self.concurrency = limit.max(1);
+        self
+    }
+
+    /// Budget for walking an address' signature history back to its first transaction,
+    /// used for the mint's creation time and creator and for wallet ages: at most
+    /// `max_pages` pages of 1000 signatures (at least 1) and `max_time` per walk
+    /// (`None` = no limit). A walk that runs out of budget reports its result as partial.
+    pub fn with_history_budget(mut self, max_pages: usize, max_time: Option<Duration>) -> Self {
+        self.history_pages = max_pages.max(1);
+        self.history_time = max_time;
+        self
+    }
+
+    /// Decode the mint's transactions into an authority timeline (`SetAuthority`,
+    /// `MintTo`, `FreezeAccount`/`ThawAccount`). Costs one `getTransaction` per
+    /// transaction, up to 200, plus a history walk per wallet that received an authority.
+    pub fn with_authority_history(mut self, enabled: bool) -> Self {
+        self.authority_history = enabled;
+        self
+    }
+
+    /// Read the transactions of the mint's first slots and record the wallets that
+    /// received tokens, with their current balances. Costs one `getTransaction` per
+    /// launch transaction (up to 100) and a balance lookup per buyer (up to 30).
+    pub fn with_launch_analysis(mut self, enabled: bool) -> Self {
+        self.launch_analysis = enabled;
+        self
+    }
+
+    /// Simulate a transfer from a top holder to a new wallet with `simulateTransaction`,
+    /// to catch tokens that can be bought but not sold. Costs an account lookup, a
+    /// `getTokenAccountsByOwner` and one simulation.
+    pub fn with_transfer_simulation(mut self, enabled: bool) -> Self {
+        self.transfer_simulation = enabled;
+        self
+    }
+
+    /// Registry of known non-user holders (default: the built-in labels)
+    pub fn with_labels(mut self, labels: LabelRegistry) -> Self {
+        self.labels = Arc::new(labels);
+        self
+    }
+
+    /// Registry of well-known tokens checked for impersonation (default: the
+    /// built-in list)
+    pub fn with_known_tokens(mut self, known_tokens: KnownTokens) -> Self {
+        self.known_tokens = Arc::new(known_tokens);
+        self
+    }
+
     /// Clear all caches
     pub fn clear_cache(&self) {
         self.token_cache.clear();
@@ -55,18 +306,308 @@ impl SolanaRpcClient {
         }
     }
 
+    /// Per-endpoint health: request counts, error rate and latency
+    pub fn endpoint_stats(&self) -> Vec<EndpointStats> {
+        self.pool.stats()
+    }
+
+    /// Time left until the analysis deadline (`None` = unlimited)
+    fn remaining(&self) -> std::result::Result<Option<Duration>, RiskError> {
+        match self.deadline {
+            None => Ok(None),
+            Some(deadline) => {
+                let now = Instant::now();
+                if now >= deadline {
+                    Err(RiskError::Timeout)
+                } else {
+                    Ok(Some(deadline - now))
+                }
+            }
+        }
+    }
+
+    /// Run one RPC call under the client's policy; see [`Self::call_served`].
+    async fn call<T, F, Fut>(&self, op: &str, request: F) -> std::result::Result<T, RiskError>
+    where
+        F: Fn(Arc<RpcClient>) -> Fut,
+        Fut: Future<Output = std::result::Result<T, ClientError>>,
+    {
+        self.call_served(op, request).await.map(|(value, _)| value)
+    }
+
+    /// Run one RPC call under the client's policy and return the endpoint that served
+    /// it, redacted to `scheme://host` so API keys don't reach reports or logs.
+    ///
+    /// Each round tries the pool's endpoints best-first, failing over immediately on
+    /// retryable errors; rounds are separated by the policy's backoff. Every attempt
+    /// is bounded by the per-request timeout and the analysis deadline.
+    async fn call_served<T, F, Fut>(
+        &self,
+        op: &str,
+        request: F,
+    ) -> std::result::Result<(T, String), RiskError>
+    where
+        F: Fn(Arc<RpcClient>) -> Fut,
+        Fut: Future<Output = std::result::Result<T, ClientError>>,
+    {
+        let mut round = 0;
+
+        loop {
+            let mut last_error = RiskError::Timeout;
+
+            let ranked = self.pool.ranked();
+            for (position, &index) in ranked.iter().enumerate() {
+                let (class, error) = match self
+                    .attempt(index, request(self.pool.client(index)))
+                    .await?
+                {
+                    Ok(value) => return Ok((value, self.pool.label(index).to_string())),
+                    Err(failure) => failure,
+                };
+
+                // The endpoint answered, the request itself was rejected: no point failing over
+                if class == ErrorClass::Other {
+                    return Err(error);
+                }
+
+                if !self.policy.should_retry(class) {
+                    return Err(error);
+                }
+
+                if position + 1 < ranked.len() {
+                    eprintln!(
+                        "{} failed on {} ({}), trying next endpoint...",
+                        op,
+                        self.pool.label(index),
+                        error
+                    );
+                }
+                last_error = error;
+            }
+
+            if round >= self.policy.max_retries {
+                return Err(last_error);
+            }
+
+            let delay = self.policy.backoff_delay(round);
+            if let Some(left) = self.remaining()? {
+                if delay >= left {
+                    return Err(RiskError::Timeout);
+                }
+            }
+
+            eprintln!(
+                "{} failed ({}), retrying in {:?}... ({} retries left)",
+                op,
+                last_error,
+                delay,
+                self.policy.max_retries - round
+            );
+            tokio::time::sleep(delay).await;
+            round += 1;
+        }
+    }
+
+    /// Send one request to one endpoint, bounded by the request timeout and the
+    /// analysis deadline, and record the outcome in the endpoint's health.
+    ///
+    /// The outer error means the deadline had already passed; the inner one is the
+    /// classified failure of the request itself.
+    async fn attempt<T, Fut>(
+        &self,
+        index: usize,
+        request: Fut,
+    ) -> std::result::Result<std::result::Result<T, (ErrorClass, RiskError)>, RiskError>
+    where
+        Fut: Future<Output = std::result::Result<T, ClientError>>,
+    {
+        let attempt_timeout = self
+            .remaining()?
+            .map(|left| left.min(self.policy.request_timeout))
+            .unwrap_or(self.policy.request_timeout);
+
+        let started = Instant::now();
+        let (class, error) = match tokio::time::timeout(attempt_timeout, request).await {
+            Ok(Ok(value)) => {
+                self.pool.record_success(index, started.elapsed());
+                return Ok(Ok(value));
+            }
+            Ok(Err(e)) => match ErrorClass::of(&e) {
+                ErrorClass::Timeout => (ErrorClass::Timeout, RiskError::Timeout),
+                class => (class, RiskError::RpcError(redact_urls(&e.to_string()))),
+            },
+            Err(_) => (ErrorClass::Timeout, RiskError::Timeout),
+        };
+
+        if class == ErrorClass::Other {
+            self.pool.record_success(index, started.elapsed());
+        } else {
+            self.pool.record_failure(index);
+        }
+
+        Ok(Err((class, error)))
+    }
+
+    /// Fetch a single account.
+    ///
+    /// Goes through `getMultipleAccounts` because `RpcClient::get_account` folds
+    /// transport errors into a plain string, which would hide 429/5xx from the policy.
+    async fn fetch_account(
+        &self,
+        pubkey: &Pubkey,
+    ) -> std::result::Result<(Account, String), RiskError> {
+        let (mut accounts, endpoint) = self
+            .call_served("getMultipleAccounts", |client| async move {
+                client
+                    .get_multiple_accounts(std::slice::from_ref(pubkey))
+                    .await
+            })
+            .await?;
+
+        match accounts.pop().flatten() {
+            Some(account) => Ok((account, endpoint)),
+            None => Err(RiskError::RpcError(format!(
+                "AccountNotFound: pubkey={}",
+                pubkey
+            ))),
+        }
+    }
+
+    /// Label known non-user holders: by owner address from the registry, then, for
+    /// the largest `MAX_LABELED_HOLDERS`, by what the owner account is
+    async fn label_holders(
+        &self,
+        holders: &mut [TokenHolder],
+    ) -> std::result::Result<(), RiskError> {
+        for holder in holders.iter_mut() {
+            holder.label = self.labels.address(&holder.address).cloned();
+        }
+
+        let pending: Vec<usize> = (0..holders.len().min(MAX_LABELED_HOLDERS))
+            .filter(|&index| holders[index].label.is_none())
+            .collect();
+        if pending.is_empty() {
+            return Ok(());
+        }
+
+        let owners: Vec<Pubkey> = pending
+            .iter()
+            .map(|&index| holders[index].address)
+            .collect();
+        let accounts = self.fetch_accounts(&owners).await?;
+
+        for (index, account) in pending.into_iter().zip(accounts) {
+            holders[index].label =
+                labels::label_owner(&self.labels, &holders[index].address, account.as_ref());
+        }
+
+        Ok(())
+    }
+
+    /// Classify the accounts holding the mint and freeze authorities with one request.
+    /// The endpoint is `None` when both authorities are revoked.
+    async fn classify_authorities(
+        &self,
+        mint_authority: Option<Pubkey>,
+        freeze_authority: Option<Pubkey>,
+    ) -> std::result::Result<AuthorityKinds, RiskError> {
+        let keys: Vec<Pubkey> = mint_authority
+            .iter()
+            .chain(&freeze_authority)
+            .copied()
+            .collect();
+        if keys.is_empty() {
+            return Ok(((None, None), None));
+        }
+
+        let (accounts, endpoint) = self
+            .call_served("getMultipleAccounts", |client| {
+                let keys = &keys;
+                async move { client.get_multiple_accounts(keys).await }
+            })
+            .await?;
+
+        let kind = |authority: Option<Pubkey>| {
+            let authority = authority?;
+            let index = keys.iter().position(|key| *key == authority)?;
+            Some(authority::classify(
+                &authority,
+                accounts.get(index).and_then(Option::as_ref),
+            ))
+        };
+
+        Ok((
+            (kind(mint_authority), kind(freeze_authority)),
+            Some(endpoint),
+        ))
+    }
+
+    /// The mint's pump.fun bonding curve with the endpoint that served it; `None`
+    /// when the mint wasn't launched on pump.fun
+    async fn fetch_bonding_curve(
+        &self,
+        mint: &Pubkey,
+    ) -> std::result::Result<Option<(BondingCurve, String)>, RiskError> {
+        let address = curve::pump_fun_curve_address(mint);
+        let (mut accounts, endpoint) = self
+            .call_served("getMultipleAccounts", |client| async move {
+                client
+                    .get_multiple_accounts(std::slice::from_ref(&address))
+                    .await
+            })
+            .await?;
+
+        let curve = accounts
+            .pop()
+            .flatten()
+            .filter(|account| account.owner == curve::PUMP_FUN_PROGRAM_ID)
+            .and_then(|account| curve::decode_pump_fun(address, &account.data));
+        Ok(curve.map(|curve| (curve, endpoint)))
+    }
+
+    /// Fetch many accounts with as few `getMultipleAccounts` requests as possible.
+    /// The result is in the order of `pubkeys`; missing accounts are `None`.
+    async fn fetch_accounts(
+        &self,
+        pubkeys: &[Pubkey],
+    ) -> std::result::Result<Vec<Option<Account>>, RiskError> {
+        let mut accounts = Vec::with_capacity(pubkeys.len());
+
+        for chunk in pubkeys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let batch = self
+                .call("getMultipleAccounts", |client| async move {
+                    client.get_multiple_accounts(chunk).await
+                })
+                .await?;
+            accounts.extend(batch);
+        }
+
+        Ok(accounts)
+    }
+
+    /// Copy of the client for one analysis; every call made through it counts
+    /// against `policy.deadline`, counted from now.
+    ///
+    /// Copies share the endpoint pool and caches but not the deadline, so
+    /// concurrent analyses don't cut each other short.
+    pub fn begin_analysis(&self) -> Self {
+        Self {
+            deadline: self.policy.deadline.map(|budget| Instant::now() + budget),
+            ..self.clone()
+        }
+    }
+
     pub async fn fetch_token_data(&self, mint: &Pubkey) -> Result<TokenData> {
         // Check cache first
         if let Some(cached) = self.token_cache.get(mint) {
             return Ok(cached);
         }
 
+        let mut endpoints = BTreeMap::new();
+
         // Fetch mint account
-        let mint_account = self
-            .client
-            .get_account(mint)
-            .await
-            .map_err(|e| RiskError::RpcError(e.to_string()))?;
+        let (mint_account, mint_endpoint) = self.fetch_account(mint).await?;
+        endpoints.insert("mint".to_string(), mint_endpoint.clone());
 
         // Verify it's an SPL token (check both Token and Token-2022 programs)
         let token_program_id = spl_token_2022::id();
@@ -74,12 +615,7 @@ impl SolanaRpcClient {
         let program_pubkey = Pubkey::from(token_program_id.to_bytes());
 
         // Also check legacy spl-token program ID
-        let legacy_token_program = Pubkey::from([
-            6, 221, 246, 225, 215, 101, 161, 147, 217, 203, 225, 70, 206, 235, 121, 172, 28, 180,
-            133, 237, 95, 91, 55, 145, 58, 140, 245, 133, 126, 255, 0, 169,
-        ]);
-
-        if owner_pubkey != program_pubkey && owner_pubkey != legacy_token_program {
+        if owner_pubkey != program_pubkey && owner_pubkey != LEGACY_TOKEN_PROGRAM_ID {
             return Err(RiskError::NotSplToken.into());
         }
 
@@ -87,16 +623,16 @@ impl SolanaRpcClient {
         let mint_data = StateWithExtensions::<Mint>::unpack(&mint_account.data)
             .map_err(|e| RiskError::ParseError(e.to_string()))?;
 
-        // Fetch data in parallel using tokio::join!
-        let (holders_result, metadata_result, creation_time_result) = tokio::join!(
-            self.fetch_token_holders(mint, mint_data.base.supply, mint_data.base.decimals),
-            self.fetch_metadata(mint),
-            self.fetch_creation_time(mint),
-        );
-
-        let holders = holders_result?;
-        let metadata = metadata_result.ok();
-        let creation_timestamp = creation_time_result.ok();
+        // Token-2022 metadata embedded in the mint needs no extra request
+        let metadata_future = async {
+            match embedded_metadata(mint, &mint_data) {
+                Some(metadata) => Ok((metadata, Some(mint_endpoint))),
+                None => {
+                    self.fetch_metadata(mint, metadata_pointer(&mint_data))
+                        .await
+                }
+            }
+        };
 
         // Convert COption to Option<Pubkey>
         let mint_authority = match mint_data.base.mint_authority {
@@ -109,22 +645,233 @@ impl SolanaRpcClient {
             COption::None => None,
         };
 
+        // Fetch data in parallel using tokio::join!
+        let (
+            holders_result,
+            metadata_result,
+            history_result,
+            quorum,
+            authorities_result,
+            curve_result,
+        ) = tokio::join!(
+            self.fetch_token_holders(mint, mint_data.base.supply, mint_data.base.decimals),
+            metadata_future,
+            self.signature_history(mint),
+            self.cross_check(mint),
+            self.classify_authorities(mint_authority, freeze_authority),
+            self.fetch_bonding_curve(mint),
+        );
+
+        let mut fetch_status = FetchStatus {
+            quorum,
+            ..FetchStatus::default()
+        };
+
+        let bonding_curve = match curve_result {
+            Ok(Some((curve, endpoint))) => {
+                endpoints.insert("bonding_curve".to_string(), endpoint);
+                Some(curve)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                fetch_status.timed_out |= e.is_timeout();
+                eprintln!("Failed to read the bonding curve: {}", e);
+                None
+            }
+        };
+
+        let (holders, holder_counts) = match holders_result {
+            Ok((mut holders, counts, endpoint)) => {
+                endpoints.insert("holders".to_string(), endpoint);
+                match self.label_holders(&mut holders).await {
+                    Ok(()) => {}
+                    Err(RiskError::Timeout) => fetch_status.timed_out = true,
+                    Err(e) => eprintln!("Failed to label holders: {}", e),
+                }
+                // The curve is known even when its owner account wasn't read
+                if let Some(curve) = &bonding_curve {
+                    for holder in holders.iter_mut().filter(|h| h.address == curve.address) {
+                        holder.label = Some(HolderLabel {
+                            kind: HolderKind::Curve,
+                            name: Some(curve.launchpad.clone()),
+                        });
+                    }
+                }
+                (holders, Some(counts))
+            }
+            Err(e) if RiskError::is_timeout_err(&e) => {
+                fetch_status.timed_out = true;
+                (Vec::new(), None)
+            }
+            Err(e) => return Err(e),
+        };
+
+        let metadata = match metadata_result {
+            Ok((metadata, endpoint)) => {
+                if let Some(endpoint) = endpoint {
+                    endpoints.insert("metadata".to_string(), endpoint);
+                }
+                Some(metadata)
+            }
+            Err(e) => {
+                fetch_status.timed_out |= RiskError::is_timeout_err(&e);
+                None
+            }
+        };
+        let impersonation = metadata.as_ref().and_then(|metadata| {
+            self.known_tokens
+                .impersonated(mint, &metadata.name, &metadata.symbol)
+        });
+
+        let (mint_authority_kind, freeze_authority_kind) = match authorities_result {
+            Ok((kinds, endpoint)) => {
+                if let Some(endpoint) = endpoint {
+                    endpoints.insert("authorities".to_string(), endpoint);
+                }
+                kinds
+            }
+            Err(e) => {
+                fetch_status.timed_out |= e.is_timeout();
+                (None, None)
+            }
+        };
+
+        let history = match history_result {
+            Ok(history) => Some(history),
+            Err(e) => {
+                fetch_status.timed_out |= RiskError::is_timeout_err(&e);
+                None
+            }
+        };
+
+        let first = history
+            .as_ref()
+            .and_then(|history| Some((history, history.signatures.last()?)));
+        let (creation_timestamp, first_signature) = match first {
+            Some((history, first)) => {
+                if first.block_time.is_some() {
+                    endpoints.insert("creation_time".to_string(), history.endpoint.clone());
+                }
+                fetch_status.creation_complete = history.complete;
+                (first.block_time, Signature::from_str(&first.signature).ok())
+            }
+            None => (None, None),
+        };
+
+        // Both come from the signature history, so they can't join the fetches above
+        let creator_future = async {
+            match first_signature {
+                Some(signature) => self.fetch_creator(mint, &signature).await,
+                None => Ok(None),
+            }
+        };
+        let authority_future = async {
+            match &history {
+                Some(history) if self.authority_history => {
+                    Some(self.fetch_authority_history(mint, history).await)
+                }
+                _ => None,
+            }
+        };
+        let launch_future = async {
+            match &history {
+                Some(history) if self.launch_analysis => self.fetch_launch(mint, history).await,
+                _ => None,
+            }
+        };
+        let simulation_future = async {
+            if self.transfer_simulation {
+                self.simulate_transfer(mint, &mint_account, &holders).await
+            } else {
+                Ok(None)
+            }
+        };
+        let (creator_result, authority_history, launch, simulation_result) = tokio::join!(
+            creator_future,
+            authority_future,
+            launch_future,
+            simulation_future
+        );
+
+        let creator = match creator_result {
+            Ok(Some((creator, endpoint))) => {
+                endpoints.insert("creator".to_string(), endpoint);
+                Some(creator)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                fetch_status.timed_out |= RiskError::is_timeout_err(&e);
+                None
+            }
+        };
+
+        let transfer_simulation = match simulation_result {
+            Ok(Some((simulation, endpoint))) => {
+                endpoints.insert("simulation".to_string(), endpoint);
+                Some(simulation)
+            }
+            Ok(None) => None,
+            Err(e) => {
+                fetch_status.timed_out |= e.is_timeout();
+                eprintln!("Transfer simulation failed: {}", e);
+                None
+            }
+        };
+
+        #[cfg(feature = "lp-analysis")]
+        let lp_pools = match self
+            .fetch_liquidity_pools(mint, mint_data.base.decimals)
+            .await
+        {
+            Ok((mut pools, endpoint)) => {
+                if let Some(endpoint) = endpoint {
+                    endpoints.insert("pools".to_string(), endpoint);
+                }
+                let creator = creator.as_ref().map(|creator| creator.address);
+                self.fill_lp_ownership(creator.as_ref(), &mut pools).await;
+                pools
+            }
+            Err(e) => {
+                fetch_status.timed_out |= e.is_timeout();
+                eprintln!("Failed to find liquidity pools: {}", e);
+                Vec::new()
+            }
+        };
+
+        // Creator balance, wallet ages and the authority history are best-effort;
+        // running out of time makes them partial
+        fetch_status.timed_out |= self.remaining().is_err();
+        fetch_status.endpoints = endpoints;
+
         let token_data = TokenData {
             mint: *mint,
             supply: mint_data.base.supply,
             decimals: mint_data.base.decimals,
             mint_authority,
             freeze_authority,
+            mint_authority_kind,
+            freeze_authority_kind,
+            extensions: extensions::decode(&mint_data),
             metadata,
             holders,
+            holder_counts,
             creation_timestamp,
+            creator,
+            authority_history,
+            launch,
+            transfer_simulation,
+            bonding_curve,
+            impersonation,
+            fetch_status,
 
             #[cfg(feature = "lp-analysis")]
-            lp_pools: Vec::new(),
+            lp_pools,
         };
 
-        // Cache the result
-        self.token_cache.insert(*mint, token_data.clone());
+        // Cache the result, unless the deadline cut it short
+        if !token_data.fetch_status.timed_out {
+            self.token_cache.insert(*mint, token_data.clone());
+        }
 
         Ok(token_data)
     }
@@ -134,238 +881,1376 @@ impl SolanaRpcClient {
         mint: &Pubkey,
         total_supply: u64,
         _decimals: u8,
-    ) -> Result<Vec<TokenHolder>> {
-        eprintln!("Fetching top token holders...");
+    ) -> Result<(Vec<TokenHolder>, HolderCounts, String)> {
+        if self.deep_holders {
+            match self.scan_holders(mint, total_supply).await {
+                Ok(scan) => return Ok(scan),
+                Err(e) if RiskError::is_timeout_err(&e) => return Err(e),
+                Err(e) => eprintln!(
+                    "Deep holder scan failed ({}), falling back to the largest accounts",
+                    e
+                ),
+            }
+        }
 
-        // Retry с экспоненциальным backoff
-        let mut retries = 3;
-        let mut delay = Duration::from_secs(2);
-
-        let largest = loop {
-            match self.client.get_token_largest_accounts(mint).await {
-                Ok(accounts) => break accounts,
-                Err(e) => {
-                    if retries == 0 {
-                        eprintln!("Failed to fetch largest accounts after retries: {}", e);
-                        return Err(RiskError::RpcError(e.to_string()).into());
-                    }
+        eprintln!("Fetching top token holders...");
 
-                    eprintln!(
-                        "Rate limited, retrying in {:?}... ({} retries left)",
-                        delay, retries
-                    );
-                    tokio::time::sleep(delay).await;
-                    delay *= 2; // Exponential backoff: 2s, 4s, 8s
-                    retries -= 1;
-                }
-            }
-        };
+        let (largest, endpoint) = self
+            .call_served("getTokenLargestAccounts", |client| async move {
+                client.get_token_largest_accounts(mint).await
+            })
+            .await
+            .map_err(|e| {
+                eprintln!("Failed to fetch largest accounts: {}", e);
+                e
+            })?;
 
         if largest.is_empty() {
             eprintln!("Warning: No holders found for this token");
-            return Ok(Vec::new());
+            return Ok((Vec::new(), HolderCounts::default(), endpoint));
         }
 
         eprintln!("Found {} top holders", largest.len());
 
-        let mut holders = Vec::new();
-
-        for account_info in largest {
-            let amount = account_info
-                .amount
-                .amount
-                .parse::<u64>()
-                .unwrap_or_else(|e| {
-                    eprintln!("Failed to parse amount: {}", e);
-                    0
-                });
+        let accounts: Vec<(Pubkey, u64)> = largest
+            .iter()
+            .filter_map(|account_info| {
+                let amount = account_info
+                    .amount
+                    .amount
+                    .parse::<u64>()
+                    .unwrap_or_else(|e| {
+                        eprintln!("Failed to parse amount: {}", e);
+                        0
+                    });
+                let address = Pubkey::from_str(&account_info.address).ok()?;
+                (amount > 0).then_some((address, amount))
+            })
+            .collect();
+
+        let token_accounts: Vec<Pubkey> = accounts.iter().map(|(address, _)| *address).collect();
+        let owners = match self.get_token_account_owners(&token_accounts).await {
+            Ok(owners) => owners,
+            Err(e) if RiskError::is_timeout_err(&e) => return Err(e),
+            Err(e) => {
+                eprintln!("Failed to resolve holder owners: {}", e);
+                vec![None; token_accounts.len()]
+            }
+        };
 
-            if amount > 0 {
-                let percentage = (amount as f64 / total_supply as f64) * 100.0;
+        // Fall back to the token account itself when its owner can't be resolved
+        let mut holders: Vec<TokenHolder> = accounts
+            .into_iter()
+            .zip(owners)
+            .map(|((token_account, amount), owner)| TokenHolder {
+                address: owner.unwrap_or(token_account),
+                amount,
+                percentage: (amount as f64 / total_supply as f64) * 100.0,
+                wallet_age_days: None,
+                wallet_age_complete: false,
+                label: None,
+                funder: None,
+            })
+            .collect();
+
+        holders.sort_by_key(|h| std::cmp::Reverse(h.amount));
+
+        // Fewer accounts than the RPC cap means we have seen all of them
+        let counts = HolderCounts {
+            total: largest.len(),
+            non_zero: holders.len(),
+            exact: largest.len() < LARGEST_ACCOUNTS_LIMIT,
+        };
 
-                let token_account_addr = match Pubkey::from_str(&account_info.address) {
-                    Ok(addr) => addr,
-                    Err(_) => continue,
-                };
+        Ok((holders, counts, endpoint))
+    }
 
-                // Добавить небольшую задержку между запросами владельцев
-                tokio::time::sleep(Duration::from_millis(100)).await;
+    /// Every holder of `mint`, aggregated by owner, from `getProgramAccounts`.
+    ///
+    /// Only the owner and amount of each token account are transferred (`dataSlice`).
+    /// Both token programs are scanned; Token-2022 accounts with extensions are
+    /// longer than the base layout and are matched by their account type byte instead.
+    async fn scan_holders(
+        &self,
+        mint: &Pubkey,
+        total_supply: u64,
+    ) -> Result<(Vec<TokenHolder>, HolderCounts, String)> {
+        eprintln!("Scanning all token accounts...");
+
+        let by_mint = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, mint.as_ref()));
+        let base_layout = RpcFilterType::DataSize(TOKEN_ACCOUNT_LEN as u64);
+        let with_extensions = RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+            TOKEN_ACCOUNT_LEN,
+            &[AccountType::Account as u8],
+        ));
 
-                let owner_pubkey = match self.get_token_account_owner(&token_account_addr).await {
-                    Ok(owner) => owner,
-                    Err(e) => {
-                        eprintln!("Failed to get owner for {}: {}", token_account_addr, e);
-                        token_account_addr
+        let queries = [
+            (LEGACY_TOKEN_PROGRAM_ID, base_layout.clone()),
+            (spl_token_2022::id(), base_layout),
+            (spl_token_2022::id(), with_extensions),
+        ];
+
+        let mut balances: HashMap<Pubkey, u64> = HashMap::new();
+        let mut endpoint = String::new();
+
+        for (program, layout) in queries {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(vec![by_mint.clone(), layout]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    data_slice: Some(UiDataSliceConfig {
+                        offset: TOKEN_ACCOUNT_OWNER_OFFSET,
+                        length: 32 + 8,
+                    }),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            };
+
+            let (accounts, served_by) = self
+                .call_served("getProgramAccounts", |client| {
+                    let config = config.clone();
+                    async move {
+                        client
+                            .get_program_ui_accounts_with_config(&program, config)
+                            .await
                     }
+                })
+                .await?;
+            endpoint = served_by;
+
+            for (_, account) in accounts {
+                let Some(data) = account.data.decode().filter(|data| data.len() >= 40) else {
+                    continue;
                 };
+                let owner = Pubkey::try_from(&data[..32]).expect("32-byte slice");
+                let amount = u64::from_le_bytes(data[32..40].try_into().expect("8-byte slice"));
 
-                holders.push(TokenHolder {
-                    address: owner_pubkey,
-                    amount,
-                    percentage,
-                    wallet_age_days: None,
-                });
+                let balance = balances.entry(owner).or_default();
+                *balance = balance.saturating_add(amount);
             }
         }
 
-        holders.sort_by(|a, b| b.amount.cmp(&a.amount));
-
-        Ok(holders)
-    }
+        let total = balances.len();
+        let mut holders: Vec<TokenHolder> = balances
+            .into_iter()
+            .filter(|(_, amount)| *amount > 0)
+            .map(|(address, amount)| TokenHolder {
+                address,
+                amount,
+                percentage: (amount as f64 / total_supply as f64) * 100.0,
+                wallet_age_days: None,
+                wallet_age_complete: false,
+                label: None,
+                funder: None,
+            })
+            .collect();
+        // Tie-break on the address so the order doesn't depend on HashMap iteration
+        holders.sort_by_key(|h| (std::cmp::Reverse(h.amount), h.address));
+
+        eprintln!(
+            "Found {} holders ({} token account owners)",
+            holders.len(),
+            total
+        );
 
-    async fn get_token_account_owner(&self, token_account: &Pubkey) -> Result<Pubkey> {
-        let account = self
-            .client
-            .get_account(token_account)
-            .await
-            .map_err(|e| RiskError::RpcError(e.to_string()))?;
+        let counts = HolderCounts {
+            total,
+            non_zero: holders.len(),
+            exact: true,
+        };
 
-        let state = StateWithExtensions::<TokenAccount>::unpack(&account.data)
-            .map_err(|e| RiskError::ParseError(e.to_string()))?;
+        Ok((holders, counts, endpoint))
+    }
 
-        Ok(Pubkey::from(state.base.owner.to_bytes()))
+    /// Owners of the given token accounts, batched; `None` where an account is missing
+    /// or isn't a token account
+    async fn get_token_account_owners(
+        &self,
+        token_accounts: &[Pubkey],
+    ) -> Result<Vec<Option<Pubkey>>> {
+        let accounts = self.fetch_accounts(token_accounts).await?;
+
+        Ok(accounts
+            .iter()
+            .map(|account| {
+                let state =
+                    StateWithExtensions::<TokenAccount>::unpack(&account.as_ref()?.data).ok()?;
+                Some(Pubkey::from(state.base.owner.to_bytes()))
+            })
+            .collect())
     }
 
-    async fn fetch_metadata(&self, mint: &Pubkey) -> Result<TokenMetadata> {
+    /// Fetch metadata from the account the mint's `MetadataPointer` names, falling
+    /// back to the Metaplex PDA; the endpoint is `None` when served from cache
+    async fn fetch_metadata(
+        &self,
+        mint: &Pubkey,
+        pointer: Option<Pubkey>,
+    ) -> Result<(TokenMetadata, Option<String>)> {
         // Check cache first
         if let Some(cached) = self.metadata_cache.get(mint) {
-            return Ok(cached);
+            return Ok((cached, None));
         }
 
-        // Derive metadata PDA using Metaplex standard
-        let metadata_seeds = &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()];
-
-        let (metadata_pda, _) =
-            Pubkey::find_program_address(metadata_seeds, &mpl_token_metadata::ID);
+        // A pointer back at the mint without the extension initialised says nothing
+        if let Some(address) = pointer.filter(|address| address != mint) {
+            match self.fetch_account(&address).await {
+                Ok((account, endpoint)) => {
+                    if let Some(token_metadata) = decode_pointed_metadata(mint, &account) {
+                        self.metadata_cache.insert(*mint, token_metadata.clone());
+                        return Ok((token_metadata, Some(endpoint)));
+                    }
+                    eprintln!(
+                        "Warning: metadata pointer {} is not readable metadata of this mint, trying Metaplex",
+                        address
+                    );
+                }
+                Err(e) if e.is_timeout() => return Err(e.into()),
+                Err(e) => eprintln!(
+                    "Warning: metadata pointer {} could not be read ({}), trying Metaplex",
+                    address, e
+                ),
+            }
+        }
 
         // Fetch metadata account
-        let account = self
-            .client
-            .get_account(&metadata_pda)
-            .await
-            .map_err(|e| RiskError::RpcError(e.to_string()))?;
+        let (account, endpoint) = self.fetch_account(&metadata_pda(mint)).await?;
 
         // Verify owner is Metaplex program
-        if account.owner != mpl_token_metadata::ID {
+        if account.owner != METADATA_PROGRAM_ID {
             return Err(RiskError::ParseError(
                 "Account is not owned by Metaplex program".to_string(),
             )
             .into());
         }
 
-        // Try to parse metadata - handle different mpl_token_metadata versions
-        // For mpl-token-metadata 5.x, the structure might be different
-        // We'll extract basic info manually if needed
+        let token_metadata = decode_metadata(&account.data);
+
+        // Cache the result
+        self.metadata_cache.insert(*mint, token_metadata.clone());
+
+        Ok((token_metadata, Some(endpoint)))
+    }
+
+    /// Signatures of `address`, paging backwards from the newest with the `before` cursor.
+    ///
+    /// The walk is complete at the first short page. When the history budget runs
+    /// out first, the oldest signature returned is not the address' first.
+    async fn signature_history(&self, address: &Pubkey) -> Result<SignatureHistory> {
+        let started = Instant::now();
+        let mut before = None;
+        let mut history = SignatureHistory {
+            signatures: Vec::new(),
+            complete: false,
+            endpoint: String::new(),
+        };
+
+        for page_number in 0.. {
+            let out_of_time = self
+                .history_time
+                .is_some_and(|budget| started.elapsed() >= budget);
+            if page_number >= self.history_pages || out_of_time {
+                break;
+            }
+
+            let (page, endpoint) = self
+                .call_served("getSignaturesForAddress", |client| async move {
+                    let config = GetConfirmedSignaturesForAddress2Config {
+                        before,
+                        until: None,
+                        limit: Some(SIGNATURE_PAGE_LIMIT),
+                        commitment: Some(CommitmentConfig::confirmed()),
+                    };
+                    client
+                        .get_signatures_for_address_with_config(address, config)
+                        .await
+                })
+                .await?;
+
+            let full = page.len() >= SIGNATURE_PAGE_LIMIT;
+            before = page
+                .last()
+                .and_then(|last| Signature::from_str(&last.signature).ok());
+            history.signatures.extend(page);
+            history.endpoint = endpoint;
+
+            // A short page, or an empty one after a full page, is the start of the history
+            if !full || before.is_none() {
+                history.complete = true;
+                break;
+            }
+        }
+
+        if !history.complete {
+            eprintln!(
+                "Signature history of {} exceeds the budget, first transaction not reached",
+                address
+            );
+        }
 
-        // Simple parsing for name, symbol, uri (first ~100 bytes typically contain this)
-        let data = &account.data;
+        Ok(history)
+    }
 
-        // Metadata structure (simplified):
-        // - key (1 byte)
-        // - update_authority (32 bytes)
-        // - mint (32 bytes)
-        // - name (4 + 32 bytes string)
-        // - symbol (4 + 10 bytes string)
-        // - uri (4 + 200 bytes string)
+    async fn get_transaction(
+        &self,
+        signature: &Signature,
+    ) -> std::result::Result<(EncodedConfirmedTransactionWithStatusMeta, String), RiskError> {
+        let config = RpcTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            max_supported_transaction_version: Some(0),
+        };
 
-        let mut offset = 1 + 32 + 32; // Skip key, update_authority, mint
+        self.call_served("getTransaction", |client| async move {
+            client.get_transaction_with_config(signature, config).await
+        })
+        .await
+    }
 
-        let name = Self::read_string(data, &mut offset)?;
-        let symbol = Self::read_string(data, &mut offset)?;
-        let uri = Self::read_string(data, &mut offset)?;
+    /// Decode the authority-related instructions of the mint's transactions.
+    ///
+    /// Up to `MAX_HISTORY_TRANSACTIONS` successful transactions are read; longer
+    /// histories keep the oldest and newest halves. Wallets that received an
+    /// authority get their first-seen time when their own history fits the budget.
+    async fn fetch_authority_history(
+        &self,
+        mint: &Pubkey,
+        history: &SignatureHistory,
+    ) -> AuthorityHistory {
+        let mut selected: Vec<RpcConfirmedTransactionStatusWithSignature> = history
+            .signatures
+            .iter()
+            .rev()
+            .filter(|status| status.err.is_none())
+            .cloned()
+            .collect();
+        let mut complete = history.complete;
+        if selected.len() > MAX_HISTORY_TRANSACTIONS {
+            let half = MAX_HISTORY_TRANSACTIONS / 2;
+            selected.drain(half..selected.len() - half);
+            complete = false;
+        }
 
-        // For verified check, we'd need to parse collection info
-        // For now, default to false (can be enhanced later)
-        let is_verified = false;
+        let transactions: Vec<_> = stream::iter(selected)
+            .map(|status| async move {
+                let signature = Signature::from_str(&status.signature)
+                    .map_err(|e| RiskError::ParseError(e.to_string()))?;
+                let (transaction, _) = self.get_transaction(&signature).await?;
+                Ok::<_, RiskError>((status, transaction))
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut events = Vec::new();
+        for result in transactions {
+            let Ok((status, transaction)) = result else {
+                complete = false;
+                continue;
+            };
+            let Some(decoded) = transaction.transaction.transaction.decode() else {
+                complete = false;
+                continue;
+            };
+
+            let actions =
+                history::decode_actions(&decoded, transaction.transaction.meta.as_ref(), mint);
+            events.extend(actions.into_iter().map(|action| AuthorityEvent {
+                signature: status.signature.clone(),
+                slot: status.slot,
+                timestamp: status.block_time.or(transaction.block_time),
+                action,
+            }));
+        }
+
+        self.fill_first_seen(&mut events).await;
+
+        AuthorityHistory { events, complete }
+    }
+
+    /// Wallets that received tokens within `LAUNCH_WINDOW_SLOTS` of the mint's creation.
+    ///
+    /// Needs the full signature history. The creator (fee payer of the first
+    /// transaction), labeled addresses and PDAs such as pools and curves are not buyers.
+    async fn fetch_launch(
+        &self,
+        mint: &Pubkey,
+        history: &SignatureHistory,
+    ) -> Option<LaunchActivity> {
+        if !history.complete {
+            eprintln!(
+                "Launch of {} not analyzed: signature history doesn't reach its creation",
+                mint
+            );
+            return None;
+        }
+
+        let creation_slot = history.signatures.last()?.slot;
+        let last_slot = creation_slot.saturating_add(LAUNCH_WINDOW_SLOTS);
+        let mut early: Vec<RpcConfirmedTransactionStatusWithSignature> = history
+            .signatures
+            .iter()
+            .rev()
+            .take_while(|status| status.slot <= last_slot)
+            .filter(|status| status.err.is_none())
+            .cloned()
+            .collect();
+        let mut complete = early.len() <= MAX_LAUNCH_TRANSACTIONS;
+        early.truncate(MAX_LAUNCH_TRANSACTIONS);
+
+        let transactions: Vec<_> = stream::iter(early)
+            .map(|status| async move {
+                let signature = Signature::from_str(&status.signature)
+                    .map_err(|e| RiskError::ParseError(e.to_string()))?;
+                let (transaction, _) = self.get_transaction(&signature).await?;
+                Ok::<_, RiskError>((status, transaction))
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut creator = None;
+        let mut creator_slots = HashSet::new();
+        let mut buyers: Vec<EarlyBuyer> = Vec::new();
+        for result in transactions {
+            let Ok((status, transaction)) = result else {
+                complete = false;
+                continue;
+            };
+            let Some(decoded) = transaction.transaction.transaction.decode() else {
+                complete = false;
+                continue;
+            };
+
+            // Transactions are oldest first, so the first fee payer created the mint
+            let fee_payer = decoded.message.static_account_keys().first().copied();
+            let creator = *creator.get_or_insert(fee_payer);
+            if fee_payer == creator {
+                creator_slots.insert(status.slot);
+            }
 
-        let token_metadata = TokenMetadata {
-            name: name.trim_matches('\0').to_string(),
-            symbol: symbol.trim_matches('\0').to_string(),
-            uri: uri.trim_matches('\0').to_string(),
-            is_verified,
+            let Some(meta) = transaction.transaction.meta.as_ref() else {
+                continue;
+            };
+            for (wallet, amount) in history::token_receipts(meta, mint) {
+                if Some(wallet) == creator
+                    || !wallet.is_on_curve()
+                    || self.labels.address(&wallet).is_some()
+                {
+                    continue;
+                }
+                match buyers.iter_mut().find(|buyer| buyer.wallet == wallet) {
+                    Some(buyer) => buyer.received = buyer.received.saturating_add(amount),
+                    None => buyers.push(EarlyBuyer {
+                        wallet,
+                        slot: status.slot,
+                        received: amount,
+                        balance: None,
+                        bundled: false,
+                    }),
+                }
+            }
+        }
+
+        for buyer in &mut buyers {
+            buyer.bundled = creator_slots.contains(&buyer.slot);
+        }
+
+        let wallets: Vec<Pubkey> = buyers
+            .iter()
+            .take(MAX_LAUNCH_BALANCES)
+            .map(|buyer| buyer.wallet)
+            .collect();
+        let balances: Vec<Option<u64>> = stream::iter(wallets)
+            .map(|wallet| async move { self.get_owner_balance(&wallet, mint).await.ok() })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        for (buyer, balance) in buyers.iter_mut().zip(balances) {
+            buyer.balance = balance;
+        }
+        // Buyers past the cap or whose balance failed to load hold an unknown amount
+        complete &= buyers.iter().all(|buyer| buyer.balance.is_some());
+
+        Some(LaunchActivity {
+            creation_slot,
+            window_slots: LAUNCH_WINDOW_SLOTS,
+            buyers,
+            complete,
+        })
+    }
+
+    /// Set `new_authority_first_seen` on authority transfers, for up to
+    /// `MAX_AUTHORITY_WALLETS` distinct wallets whose history fits the budget
+    async fn fill_first_seen(&self, events: &mut [AuthorityEvent]) {
+        let mut wallets: Vec<Pubkey> = Vec::new();
+        for event in events.iter() {
+            if let AuthorityAction::SetAuthority {
+                new_authority: Some(wallet),
+                ..
+            } = &event.action
+            {
+                if !wallets.contains(wallet) && wallets.len() < MAX_AUTHORITY_WALLETS {
+                    wallets.push(*wallet);
+                }
+            }
+        }
+
+        let first_seen: HashMap<Pubkey, i64> = stream::iter(wallets)
+            .map(|wallet| async move {
+                let history = self.signature_history(&wallet).await.ok()?;
+                // Only a complete walk has found the wallet's first transaction
+                if !history.complete {
+                    return None;
+                }
+                Some((wallet, history.signatures.last()?.block_time?))
+            })
+            .buffer_unordered(self.concurrency)
+            .filter_map(|entry| async move { entry })
+            .collect()
+            .await;
+
+        for event in events.iter_mut() {
+            if let AuthorityAction::SetAuthority {
+                new_authority: Some(wallet),
+                new_authority_first_seen,
+                ..
+            } = &mut event.action
+            {
+                *new_authority_first_seen = first_seen.get(wallet).copied();
+            }
+        }
+    }
+
+    /// Identify the creator as the fee payer of the transaction that initialized the
+    /// mint, and look up their current balance and wallet age.
+    ///
+    /// `None` when `signature` does not initialize the mint, e.g. because the history
+    /// was too long to reach the first transaction.
+    async fn fetch_creator(
+        &self,
+        mint: &Pubkey,
+        signature: &Signature,
+    ) -> Result<Option<(TokenCreator, String)>> {
+        let (transaction, endpoint) = self.get_transaction(signature).await?;
+
+        let address =
+            transaction.transaction.transaction.decode().and_then(|tx| {
+                creator::find_creator(&tx, transaction.transaction.meta.as_ref(), mint)
+            });
+
+        let Some(address) = address else {
+            eprintln!(
+                "Warning: transaction {} does not initialize {}, creator unknown",
+                signature, mint
+            );
+            return Ok(None);
         };
 
-        // Cache the result
-        self.metadata_cache.insert(*mint, token_metadata.clone());
+        let (balance, wallet_age) = tokio::join!(
+            self.get_owner_balance(&address, mint),
+            self.get_wallet_age(&address),
+        );
+
+        let wallet_age = wallet_age.ok().flatten();
+        let creator = TokenCreator {
+            address,
+            balance: balance.ok(),
+            wallet_age_days: wallet_age.map(|age| age.days),
+            wallet_age_complete: wallet_age.is_some_and(|age| age.complete),
+        };
+
+        Ok(Some((creator, endpoint)))
+    }
+
+    /// Balance of `mint` summed over all of `owner`'s token accounts
+    async fn get_owner_balance(&self, owner: &Pubkey, mint: &Pubkey) -> Result<u64> {
+        let balance = self
+            .get_owner_token_accounts(owner, mint)
+            .await?
+            .into_iter()
+            .map(|(_, amount)| amount)
+            .fold(0u64, u64::saturating_add);
+
+        Ok(balance)
+    }
 
-        Ok(token_metadata)
+    /// `owner`'s token accounts of `mint` with their balances
+    async fn get_owner_token_accounts(
+        &self,
+        owner: &Pubkey,
+        mint: &Pubkey,
+    ) -> std::result::Result<Vec<(Pubkey, u64)>, RiskError> {
+        let accounts = self
+            .call("getTokenAccountsByOwner", |client| async move {
+                client
+                    .get_token_accounts_by_owner(owner, TokenAccountsFilter::Mint(*mint))
+                    .await
+            })
+            .await?;
+
+        // The client requests `jsonParsed`; amounts are decimal strings in base units
+        let accounts = accounts
+            .iter()
+            .filter_map(|keyed| {
+                let UiAccountData::Json(parsed) = &keyed.account.data else {
+                    return None;
+                };
+                let amount = parsed
+                    .parsed
+                    .pointer("/info/tokenAmount/amount")?
+                    .as_str()?
+                    .parse::<u64>()
+                    .ok()?;
+                Some((Pubkey::from_str(&keyed.pubkey).ok()?, amount))
+            })
+            .collect();
+
+        Ok(accounts)
     }
 
-    fn read_string(data: &[u8], offset: &mut usize) -> Result<String> {
-        if *offset + 4 > data.len() {
-            return Ok(String::new());
+    /// Raydium, Orca Whirlpool and Meteora DLMM pools trading `mint`: the
+    /// `MAX_LIQUIDITY_POOLS` largest by reserve, largest first, with the endpoint
+    /// that served the pool search.
+    ///
+    /// Every program/mint-side pair is one `getProgramAccounts` query; reserves,
+    /// quote decimals, LP supplies and the SOL/USD oracle then come from one batch
+    /// of account reads. Pools quoted in SOL are valued at the oracle's price.
+    #[cfg(feature = "lp-analysis")]
+    async fn fetch_liquidity_pools(
+        &self,
+        mint: &Pubkey,
+        decimals: u8,
+    ) -> std::result::Result<(Vec<LiquidityPool>, Option<String>), RiskError> {
+        eprintln!("Searching liquidity pools...");
+
+        let mut found: Vec<(&PoolProgram, Pubkey, PoolLayout)> = Vec::new();
+        let mut endpoint = None;
+
+        for program in liquidity::POOL_PROGRAMS {
+            for offset in program.mint_offsets {
+                let config = RpcProgramAccountsConfig {
+                    filters: Some(vec![
+                        RpcFilterType::DataSize(program.size),
+                        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, mint.as_ref())),
+                    ]),
+                    account_config: RpcAccountInfoConfig {
+                        encoding: Some(UiAccountEncoding::Base64),
+                        ..RpcAccountInfoConfig::default()
+                    },
+                    ..RpcProgramAccountsConfig::default()
+                };
+
+                let (accounts, served_by) = self
+                    .call_served("getProgramAccounts", |client| {
+                        let config = config.clone();
+                        async move {
+                            client
+                                .get_program_ui_accounts_with_config(&program.program, config)
+                                .await
+                        }
+                    })
+                    .await?;
+                endpoint = Some(served_by);
+
+                found.extend(accounts.into_iter().filter_map(|(address, account)| {
+                    let layout = program.decode(&account.data.decode()?)?;
+                    Some((program, address, layout))
+                }));
+            }
         }
 
-        let len = u32::from_le_bytes([
-            data[*offset],
-            data[*offset + 1],
-            data[*offset + 2],
-            data[*offset + 3],
-        ]) as usize;
+        // Per pool: both vaults, the quote mint and the LP mint when there is one
+        let quote_side = |layout: &PoolLayout| usize::from(layout.mints[0] == *mint);
+        let keys: Vec<Vec<Pubkey>> = found
+            .iter()
+            .map(|(_, _, layout)| {
+                let mut keys = vec![
+                    layout.vaults[0],
+                    layout.vaults[1],
+                    layout.mints[quote_side(layout)],
+                ];
+                keys.extend(layout.lp.map(|(lp_mint, _)| lp_mint));
+                keys
+            })
+            .collect();
+        // The SOL/USD oracle rides along to value SOL pairs
+        let mut batch = vec![price::PYTH_SOL_USD_PRICE_ACCOUNT];
+        batch.extend(keys.concat());
+        let mut accounts = self.fetch_accounts(&batch).await?.into_iter();
+        let now = chrono::Utc::now().timestamp();
+        let sol_usd = accounts
+            .next()
+            .flatten()
+            .and_then(|account| price::decode_pyth(&account.data))
+            .and_then(|price| price.value(now));
+
+        let token_amount = |account: &Option<Account>| {
+            StateWithExtensions::<TokenAccount>::unpack(&account.as_ref()?.data)
+                .ok()
+                .map(|state| state.base.amount)
+        };
+        let mint_state = |account: &Option<Account>| {
+            StateWithExtensions::<Mint>::unpack(&account.as_ref()?.data)
+                .ok()
+                .map(|state| state.base)
+        };
+        let mut pools: Vec<LiquidityPool> = found
+            .into_iter()
+            .zip(&keys)
+            .filter_map(|((program, pool_address, layout), keys)| {
+                let accounts: Vec<Option<Account>> = accounts.by_ref().take(keys.len()).collect();
+                let reserves = [0, 1].map(|side| {
+                    token_amount(&accounts[side])
+                        .map(|amount| amount.saturating_sub(layout.pending[side]))
+                });
+                let quote = quote_side(&layout);
+                let side = 1 - quote;
+                let quote_decimals = mint_state(&accounts[2])?.decimals;
+
+                let mut pool_decimals = [decimals; 2];
+                pool_decimals[quote] = quote_decimals;
+                let (token_reserve, quote_reserve) = (reserves[side]?, reserves[quote]?);
+                let price = liquidity::price(
+                    layout.price,
+                    side,
+                    pool_decimals,
+                    [reserves[0]?, reserves[1]?],
+                );
+                let value = liquidity::value_in_quote(
+                    token_reserve as f64 / 10f64.powi(decimals as i32),
+                    quote_reserve as f64 / 10f64.powi(quote_decimals as i32),
+                    price,
+                );
+
+                let quote_usd = price::quote_usd(&layout.mints[quote], sol_usd);
+
+                let lp_token = match layout.lp {
+                    Some((lp_mint, issued)) => {
+                        let supply = mint_state(&accounts[3])?.supply;
+                        Some(LpToken {
+                            mint: lp_mint,
+                            issued,
+                            supply,
+                            burned_pct: liquidity::burned_pct(issued, supply),
+                            creator_pct: None,
+                        })
+                    }
+                    None => None,
+                };
 
-        *offset += 4;
+                Some(LiquidityPool {
+                    dex: program.dex.to_string(),
+                    kind: program.kind,
+                    pool_address,
+                    quote_mint: layout.mints[quote],
+                    token_reserve,
+                    quote_reserve,
+                    quote_decimals,
+                    price,
+                    price_usd: price.zip(quote_usd).map(|(price, usd)| price * usd),
+                    lp_token,
+                    positions: None,
+                    tvl_usd: quote_usd.map(|usd| value * usd),
+                    lp_locked: false,
+                    locks: Vec::new(),
+                    opened_by: layout.creator,
+                    creator_is_lp_provider: false,
+                })
+            })
+            .collect();
+        // Only the largest pools matter, and dust pools can outnumber them by far;
+        // cut after sorting so the main pool is never dropped
+        pools.sort_by_key(|pool| std::cmp::Reverse(pool.token_reserve));
+        eprintln!("Found {} liquidity pool(s)", pools.len());
+        pools.truncate(MAX_LIQUIDITY_POOLS);
+        Ok((pools, endpoint))
+    }
 
-        if *offset + len > data.len() {
-            return Ok(String::new());
+    /// Who provides and who locked each pool's liquidity: the creator's share of the
+    /// LP supply and the locks holding LP tokens, or the creator's and lockers'
+    /// shares of the positions. Best-effort; a pool whose holders can't be read
+    /// keeps them unset.
+    #[cfg(feature = "lp-analysis")]
+    async fn fill_lp_ownership(&self, creator: Option<&Pubkey>, pools: &mut [LiquidityPool]) {
+        let now = chrono::Utc::now().timestamp();
+
+        for pool in pools.iter_mut() {
+            if let Some(lp_token) = &mut pool.lp_token {
+                if let Some(creator) = creator {
+                    let balance = self.get_owner_balance(creator, &lp_token.mint).await.ok();
+                    if let Some(balance) = balance {
+                        lp_token.creator_pct = Some(liquidity::share_pct(balance, lp_token.supply));
+                    }
+                    pool.creator_is_lp_provider = pool.opened_by == Some(*creator)
+                        || balance.is_some_and(|balance| balance > 0);
+                }
+
+                match self.fetch_locks(&lp_token.mint).await {
+                    Ok(locks) => {
+                        pool.locks = locks
+                            .into_iter()
+                            .map(|(locker, address, terms)| LpLock {
+                                locker: locker.to_string(),
+                                address,
+                                beneficiary: terms.beneficiary,
+                                pct: liquidity::share_pct(
+                                    terms.schedule.locked_at(now),
+                                    lp_token.supply,
+                                ),
+                                unlock_at: terms.schedule.end(),
+                                total_pct: liquidity::share_pct(
+                                    terms.schedule.total,
+                                    lp_token.supply,
+                                ),
+                                schedule: terms.schedule,
+                            })
+                            .collect();
+                    }
+                    Err(e) => {
+                        eprintln!("Failed to search locks of LP mint {}: {}", lp_token.mint, e)
+                    }
+                }
+            } else if let Some(program) = liquidity::program(&pool.dex) {
+                let Some(layout) = &program.positions else {
+                    continue;
+                };
+                let ownership = self
+                    .fetch_position_ownership(
+                        &program.program,
+                        layout,
+                        &pool.pool_address,
+                        creator,
+                        now,
+                    )
+                    .await;
+                match ownership {
+                    Ok((ownership, locks)) => {
+                        pool.creator_is_lp_provider = creator.is_some_and(|creator| {
+                            pool.opened_by == Some(*creator)
+                                || ownership.creator_pct.is_some_and(|pct| pct > 0.0)
+                        });
+                        pool.positions = Some(ownership);
+                        pool.locks = locks;
+                    }
+                    Err(e) => eprintln!(
+                        "Failed to read positions of pool {}: {}",
+                        pool.pool_address, e
+                    ),
+                }
+            }
+
+            pool.locks.retain(|lock| lock.pct > 0.0);
+            pool.locks.sort_by_key(|lock| lock.unlock_at);
+            pool.lp_locked = !pool.locks.is_empty()
+                || pool
+                    .positions
+                    .as_ref()
+                    .is_some_and(|positions| positions.locked_pct > 0.0);
         }
+    }
 
-        let string = String::from_utf8_lossy(&data[*offset..*offset + len]).to_string();
-        *offset += len;
+    /// Locks of `mint` in the known locker programs, with each lock account's address
+    #[cfg(feature = "lp-analysis")]
+    async fn fetch_locks(
+        &self,
+        mint: &Pubkey,
+    ) -> std::result::Result<Vec<(&'static str, Pubkey, LockTerms)>, RiskError> {
+        let mut locks = Vec::new();
+
+        for locker in locker::LOCKER_PROGRAMS {
+            let config = RpcProgramAccountsConfig {
+                filters: Some(vec![
+                    RpcFilterType::DataSize(locker.size),
+                    RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                        locker.mint_offset,
+                        mint.as_ref(),
+                    )),
+                ]),
+                account_config: RpcAccountInfoConfig {
+                    encoding: Some(UiAccountEncoding::Base64),
+                    ..RpcAccountInfoConfig::default()
+                },
+                ..RpcProgramAccountsConfig::default()
+            };
+            let accounts = self
+                .call("getProgramAccounts", |client| {
+                    let config = config.clone();
+                    async move {
+                        client
+                            .get_program_ui_accounts_with_config(&locker.program, config)
+                            .await
+                    }
+                })
+                .await?;
+
+            locks.extend(accounts.into_iter().filter_map(|(address, account)| {
+                let terms = locker.decode(&account.data.decode()?)?;
+                Some((locker.name, address, terms))
+            }));
+        }
+
+        Ok(locks)
+    }
+
+    /// Positions of a concentrated-liquidity pool, the shares of its liquidity held
+    /// by the creator and by known lockers, and the locks holding position NFTs.
+    /// Only the largest `MAX_OWNED_POSITIONS` get their owners resolved; a position
+    /// NFT belongs to the owner of its largest token account, and one held by a
+    /// program is looked up in the locker programs.
+    #[cfg(feature = "lp-analysis")]
+    async fn fetch_position_ownership(
+        &self,
+        program: &Pubkey,
+        layout: &PositionProgram,
+        pool: &Pubkey,
+        creator: Option<&Pubkey>,
+        now: i64,
+    ) -> Result<(PositionOwnership, Vec<LpLock>)> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(layout.size),
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(
+                    layout.pool_offset,
+                    pool.as_ref(),
+                )),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice: Some(UiDataSliceConfig {
+                    offset: layout.slice.0,
+                    length: layout.slice.1,
+                }),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+        let accounts = self
+            .call("getProgramAccounts", |client| {
+                let config = config.clone();
+                async move {
+                    client
+                        .get_program_ui_accounts_with_config(program, config)
+                        .await
+                }
+            })
+            .await?;
+
+        let mut positions: Vec<(PositionHolder, u128)> = accounts
+            .into_iter()
+            .filter_map(|(_, account)| layout.decode(&account.data.decode()?))
+            .filter(|(_, liquidity)| *liquidity > 0)
+            .collect();
+        positions.sort_by_key(|(_, liquidity)| std::cmp::Reverse(*liquidity));
+
+        // The wallet or program behind each of the largest positions
+        let holders: Vec<PositionHolder> = positions
+            .iter()
+            .take(MAX_OWNED_POSITIONS)
+            .map(|(holder, _)| *holder)
+            .collect();
+        let nft_accounts: Vec<Option<Pubkey>> = stream::iter(holders.clone())
+            .map(|holder| async move {
+                let PositionHolder::Nft(position_mint) = holder else {
+                    return None;
+                };
+                let largest = self
+                    .call("getTokenLargestAccounts", |client| async move {
+                        client.get_token_largest_accounts(&position_mint).await
+                    })
+                    .await
+                    .ok()?;
+                let account = largest.into_iter().find(|account| {
+                    account
+                        .amount
+                        .amount
+                        .parse::<u64>()
+                        .is_ok_and(|amount| amount > 0)
+                })?;
+                Pubkey::from_str(&account.address).ok()
+            })
+            .buffered(self.concurrency)
+            .collect()
+            .await;
+        let nft_owners = self
+            .get_token_account_owners(&nft_accounts.iter().flatten().copied().collect::<Vec<_>>())
+            .await?;
+        let mut nft_owners = nft_owners.into_iter();
+        let owners: Vec<Option<Pubkey>> = holders
+            .iter()
+            .zip(&nft_accounts)
+            .map(|(holder, nft_account)| match holder {
+                PositionHolder::Owner(owner) => Some(*owner),
+                PositionHolder::Nft(_) => nft_account.and_then(|_| nft_owners.next().flatten()),
+            })
+            .collect();
+
+        let owner_keys: Vec<Pubkey> = owners.iter().flatten().copied().collect();
+        let owner_accounts = self.fetch_accounts(&owner_keys).await?;
+        let locked: HashSet<Pubkey> = owner_keys
+            .iter()
+            .zip(&owner_accounts)
+            .filter(|(owner, account)| {
+                let label = self
+                    .labels
+                    .address(owner)
+                    .cloned()
+                    .or_else(|| labels::label_owner(&self.labels, owner, account.as_ref()));
+                label.is_some_and(|label| label.kind == HolderKind::Locker)
+            })
+            .map(|(owner, _)| *owner)
+            .collect();
+
+        let mut positions: Vec<(u128, Option<Pubkey>, bool)> = positions
+            .iter()
+            .enumerate()
+            .map(|(index, (_, liquidity))| {
+                let owner = owners.get(index).copied().flatten();
+                let locked = owner.is_some_and(|owner| locked.contains(&owner));
+                (*liquidity, owner, locked)
+            })
+            .collect();
+
+        // Lockers keep position NFTs in escrow accounts owned by PDAs
+        let total: f64 = positions
+            .iter()
+            .map(|(liquidity, _, _)| *liquidity as f64)
+            .sum();
+        let mut locks = Vec::new();
+        for (index, holder) in holders.iter().enumerate() {
+            let PositionHolder::Nft(position_mint) = holder else {
+                continue;
+            };
+            if !owners[index].is_some_and(|owner| !owner.is_on_curve()) {
+                continue;
+            }
+            for (locker, address, terms) in self.fetch_locks(position_mint).await? {
+                let (liquidity, _, locked) = &mut positions[index];
+                let lock = LpLock {
+                    locker: locker.to_string(),
+                    address,
+                    beneficiary: terms.beneficiary,
+                    pct: 0.0,
+                    unlock_at: terms.schedule.end(),
+                    total_pct: *liquidity as f64 / total * 100.0,
+                    schedule: terms.schedule,
+                };
+                let lock = LpLock {
+                    pct: lock.locked_pct_at(now),
+                    ..lock
+                };
+                *locked |= lock.pct > 0.0;
+                locks.push(lock);
+            }
+        }
 
-        Ok(string)
+        Ok((liquidity::position_ownership(&positions, creator), locks))
     }
 
-    async fn fetch_creation_time(&self, mint: &Pubkey) -> Result<i64> {
-        use solana_client::rpc_config::RpcTransactionConfig;
-        use solana_transaction_status::UiTransactionEncoding;
+    /// Simulate a transfer of 1% of a top user holder's balance to a new wallet,
+    /// creating the wallet's associated token account in the same transaction.
+    ///
+    /// The holder pays and signs, so only wallets with SOL qualify; signatures
+    /// aren't verified and the blockhash is replaced by the node. `None` when no
+    /// holder qualifies or the simulation failed for reasons unrelated to the token.
+    async fn simulate_transfer(
+        &self,
+        mint: &Pubkey,
+        mint_account: &Account,
+        holders: &[TokenHolder],
+    ) -> std::result::Result<Option<(TransferSimulation, String)>, RiskError> {
+        let candidates: Vec<Pubkey> = holders
+            .iter()
+            .filter(|holder| holder.label.is_none() && holder.address.is_on_curve())
+            .map(|holder| holder.address)
+            .take(MAX_SIMULATION_CANDIDATES)
+            .collect();
+        let wallets = self.fetch_accounts(&candidates).await?;
+        let Some(holder) = candidates
+            .into_iter()
+            .zip(wallets)
+            .find_map(|(address, wallet)| {
+                let wallet = wallet?;
+                (wallet.owner == SYSTEM_PROGRAM_ID && wallet.lamports >= MIN_SIMULATION_LAMPORTS)
+                    .then_some(address)
+            })
+        else {
+            eprintln!("Transfer not simulated: no top holder wallet can pay for it");
+            return Ok(None);
+        };
 
-        let _config = RpcTransactionConfig {
-            encoding: Some(UiTransactionEncoding::Json),
+        let Some((source, balance)) = self
+            .get_owner_token_accounts(&holder, mint)
+            .await?
+            .into_iter()
+            .max_by_key(|(_, amount)| *amount)
+            .filter(|(_, amount)| *amount > 0)
+        else {
+            return Ok(None);
+        };
+
+        let mint_data = StateWithExtensions::<Mint>::unpack(&mint_account.data)
+            .map_err(|e| RiskError::ParseError(e.to_string()))?;
+        let decimals = mint_data.base.decimals;
+        let amount = (balance / 100).max(1);
+        let program = mint_account.owner;
+
+        let recipient = Keypair::new().pubkey();
+        let destination = get_associated_token_address_with_program_id(&recipient, mint, &program);
+
+        // Resolves the accounts a transfer hook needs, like a wallet would
+        let transfer = create_transfer_checked_instruction_with_extra_metas(
+            &program,
+            &source,
+            mint,
+            &destination,
+            &holder,
+            &[],
+            amount,
+            decimals,
+            |address| async move {
+                self.fetch_accounts(std::slice::from_ref(&address))
+                    .await
+                    .map(|mut accounts| accounts.pop().flatten().map(|account| account.data))
+                    .map_err(|e| Box::new(e) as AccountFetchError)
+            },
+        )
+        .await
+        .map_err(|e| {
+            e.downcast::<RiskError>()
+                .map(|e| *e)
+                .unwrap_or_else(|e| RiskError::RpcError(e.to_string()))
+        })?;
+
+        // The transfer must stay at `simulation::TRANSFER_INSTRUCTION`
+        let instructions = [
+            create_associated_token_account_idempotent(&holder, &recipient, mint, &program),
+            transfer,
+        ];
+        let message = Message::new(&instructions, Some(&holder));
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default(); message.header.num_required_signatures as usize],
+            message: VersionedMessage::Legacy(message),
+        };
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
             commitment: Some(CommitmentConfig::confirmed()),
-            max_supported_transaction_version: Some(0),
+            encoding: Some(UiTransactionEncoding::Base64),
+            accounts: Some(RpcSimulateTransactionAccountsConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                addresses: vec![destination.to_string()],
+            }),
+            ..RpcSimulateTransactionConfig::default()
         };
 
-        let signatures = self
-            .client
-            .get_signatures_for_address(mint)
-            .await
-            .map_err(|e| RiskError::RpcError(e.to_string()))?;
+        let (response, endpoint) = self
+            .call_served("simulateTransaction", |client| {
+                let (transaction, config) = (&transaction, config.clone());
+                async move {
+                    client
+                        .simulate_transaction_with_config(transaction, config)
+                        .await
+                }
+            })
+            .await?;
+        let result = response.value;
+
+        let destination_account = result
+            .accounts
+            .and_then(|accounts| accounts.into_iter().next().flatten())
+            .and_then(|account| account.decode::<Account>());
+        let error = result.err.map(TransactionError::from);
+        let Some(outcome) = simulation::outcome(
+            error.clone(),
+            result.logs.unwrap_or_default(),
+            destination_account.as_ref(),
+        ) else {
+            if let Some(error) = error {
+                eprintln!("Transfer simulation inconclusive: {}", error);
+            }
+            return Ok(None);
+        };
+
+        let simulation = TransferSimulation {
+            holder,
+            amount,
+            declared_fee: simulation::declared_fee(&mint_data, amount),
+            outcome,
+        };
+        Ok(Some((simulation, endpoint)))
+    }
 
-        if let Some(sig) = signatures.last() {
-            if let Some(block_time) = sig.block_time {
-                return Ok(block_time);
+    /// Read the critical accounts from the quorum endpoints; `None` when quorum mode is off.
+    ///
+    /// The first endpoint that answers pins the context slot; the others are asked
+    /// concurrently for state at least that fresh, so lagging nodes are rejected
+    /// instead of reported as disagreeing.
+    async fn cross_check(&self, mint: &Pubkey) -> Option<QuorumCheck> {
+        if self.quorum == 0 {
+            return None;
+        }
+
+        let keys = [*mint, metadata_pda(mint)];
+        let selected: Vec<usize> = self.pool.ranked().into_iter().take(self.quorum).collect();
+
+        let mut check = QuorumCheck {
+            requested: self.quorum,
+            ..QuorumCheck::default()
+        };
+        let mut reads = Vec::new();
+
+        let mut rest = selected.iter();
+        for &index in rest.by_ref() {
+            match self.read_critical(index, &keys, None).await {
+                Ok((slot, fields)) => {
+                    check.min_context_slot = Some(slot);
+                    reads.push((self.pool.label(index).to_string(), fields));
+                    break;
+                }
+                Err(e) => check
+                    .unavailable
+                    .push(format!("{} ({})", self.pool.label(index), e)),
             }
         }
 
-        Err(RiskError::ParseError("No creation time found".to_string()).into())
+        let min_context_slot = check.min_context_slot;
+        let results = futures::future::join_all(rest.map(|&index| async move {
+            (
+                index,
+                self.read_critical(index, &keys, min_context_slot).await,
+            )
+        }))
+        .await;
+
+        for (index, result) in results {
+            let url = self.pool.label(index).to_string();
+            match result {
+                Ok((_, fields)) => reads.push((url, fields)),
+                Err(e) => check.unavailable.push(format!("{} ({})", url, e)),
+            }
+        }
+
+        check.responded = reads.len();
+        check.disagreements = quorum::compare(&reads);
+        for disagreement in &check.disagreements {
+            eprintln!("Warning: RPC endpoints disagree on {}", disagreement);
+        }
+
+        Some(check)
     }
 
-    async fn get_wallet_age(&self, wallet: &Pubkey) -> Result<u64> {
+    /// Read the mint and its metadata PDA from one endpoint, at or after `min_context_slot`.
+    /// Returns the context slot of the answer.
+    async fn read_critical(
+        &self,
+        index: usize,
+        keys: &[Pubkey; 2],
+        min_context_slot: Option<u64>,
+    ) -> std::result::Result<(u64, CriticalFields), RiskError> {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            min_context_slot,
+            ..RpcAccountInfoConfig::default()
+        };
+
+        let client = self.pool.client(index);
+        let response = self
+            .attempt(
+                index,
+                client.get_multiple_ui_accounts_with_config(keys, config),
+            )
+            .await?
+            .map_err(|(_, e)| e)?;
+
+        let mut accounts = response
+            .value
+            .into_iter()
+            .map(|account| account.and_then(|a| a.decode::<Account>()));
+
+        let mint = accounts
+            .next()
+            .flatten()
+            .ok_or_else(|| RiskError::RpcError(format!("AccountNotFound: pubkey={}", keys[0])))?;
+        let metadata = accounts.next().flatten();
+
+        let fields = CriticalFields::decode(&keys[0], &mint, metadata.as_ref())?;
+        Ok((response.context.slot, fields))
+    }
+
+    /// Age of `wallet` from its oldest transaction; `None` when the wallet has
+    /// no dated transaction, which says nothing about its age
+    async fn get_wallet_age(&self, wallet: &Pubkey) -> Result<Option<WalletAge>> {
         // Check cache first
         if let Some(cached) = self.wallet_age_cache.get(wallet) {
-            return Ok(cached);
+            return Ok(Some(cached));
         }
 
-        let signatures = self
-            .client
-            .get_signatures_for_address(wallet)
-            .await
-            .map_err(|e| RiskError::RpcError(e.to_string()))?;
-
-        if let Some(first_tx) = signatures.last() {
+        let history = self.signature_history(wallet).await?;
+        if let Some(first_tx) = history.signatures.last() {
             if let Some(block_time) = first_tx.block_time {
+                let funder = if history.complete {
+                    match self.find_funder(wallet, &history).await {
+                        Ok(funder) => funder,
+                        Err(e) if e.is_timeout() => return Err(e.into()),
+                        Err(_) => None,
+                    }
+                } else {
+                    None
+                };
+
                 let now = chrono::Utc::now().timestamp();
                 let age_seconds = now - block_time;
-                let age_days = (age_seconds / 86400).max(0) as u64;
+                let age = WalletAge {
+                    days: (age_seconds / 86400).max(0) as u64,
+                    complete: history.complete,
+                    funder,
+                };
 
                 // Cache the result
-                self.wallet_age_cache.insert(*wallet, age_days);
+                self.wallet_age_cache.insert(*wallet, age);
 
-                return Ok(age_days);
+                return Ok(Some(age));
             }
         }
 
-        Ok(0)
+        Ok(None)
+    }
+
+    /// Sender of the first incoming SOL transfer among the wallet's oldest
+    /// `FUNDING_LOOKUPS` successful transactions.
+    ///
+    /// Labeled funders (exchanges, ...) are dropped: withdrawals from a shared hot
+    /// wallet say nothing about common control.
+    async fn find_funder(
+        &self,
+        wallet: &Pubkey,
+        history: &SignatureHistory,
+    ) -> std::result::Result<Option<Pubkey>, RiskError> {
+        let oldest = history
+            .signatures
+            .iter()
+            .rev()
+            .filter(|status| status.err.is_none())
+            .take(FUNDING_LOOKUPS);
+
+        for status in oldest {
+            let Ok(signature) = Signature::from_str(&status.signature) else {
+                continue;
+            };
+            let (transaction, _) = self.get_transaction(&signature).await?;
+            let Some(decoded) = transaction.transaction.transaction.decode() else {
+                continue;
+            };
+
+            let meta = transaction.transaction.meta.as_ref();
+            if let Some(funder) = history::funding_source(&decoded, meta, wallet) {
+                return Ok(self.labels.address(&funder).is_none().then_some(funder));
+            }
+        }
+
+        Ok(None)
     }
 
     /// Populate wallet ages for top holders
@@ -373,25 +2258,42 @@ impl SolanaRpcClient {
         // Only check top 10 holders to avoid too many RPC calls
         let limit = holders.len().min(10);
 
-        for holder in holders.iter_mut().take(limit) {
-            if holder.wallet_age_days.is_none() {
-                if let Ok(age) = self.get_wallet_age(&holder.address).await {
-                    holder.wallet_age_days = Some(age);
+        let pending: Vec<(usize, Pubkey)> = holders
+            .iter()
+            .take(limit)
+            .enumerate()
+            .filter(|(_, holder)| holder.wallet_age_days.is_none() && holder.label.is_none())
+            .map(|(index, holder)| (index, holder.address))
+            .collect();
+
+        let ages: Vec<(usize, Result<Option<WalletAge>>)> = stream::iter(pending)
+            .map(|(index, wallet)| async move { (index, self.get_wallet_age(&wallet).await) })
+            .buffer_unordered(self.concurrency)
+            .collect()
+            .await;
+
+        let mut timed_out = None;
+        for (index, age) in ages {
+            match age {
+                Ok(Some(age)) => {
+                    holders[index].wallet_age_days = Some(age.days);
+                    holders[index].wallet_age_complete = age.complete;
+                    holders[index].funder = age.funder;
                 }
+                Ok(None) => {}
+                // Out of time: report it, but keep the ages that did arrive
+                Err(e) if RiskError::is_timeout_err(&e) => timed_out = Some(e),
+                Err(_) => {}
             }
         }
 
-        Ok(())
+        match timed_out {
+            Some(e) => Err(e),
+            None => Ok(()),
+        }
     }
 }
 
-// Placeholder for mpl_token_metadata
-mod mpl_token_metadata {
-    use solana_sdk::pubkey::Pubkey;
-
-    pub const ID: Pubkey = solana_sdk::pubkey!("metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s");
-}
-
 use crate::cache::CacheStats;
 
 #[derive(Debug, Clone)]
@@ -404,7 +2306,19 @@ pub struct CacheStatsSummary {
 impl Clone for SolanaRpcClient {
     fn clone(&self) -> Self {
         Self {
-            client: Arc::clone(&self.client),
+            pool: Arc::clone(&self.pool),
+            policy: self.policy.clone(),
+            deadline: self.deadline,
+            quorum: self.quorum,
+            deep_holders: self.deep_holders,
+            concurrency: self.concurrency,
+            authority_history: self.authority_history,
+            launch_analysis: self.launch_analysis,
+            transfer_simulation: self.transfer_simulation,
+            history_pages: self.history_pages,
+            history_time: self.history_time,
+            labels: Arc::clone(&self.labels),
+            known_tokens: Arc::clone(&self.known_tokens),
             token_cache: self.token_cache.clone(),
             metadata_cache: self.metadata_cache.clone(),
             wallet_age_cache: self.wallet_age_cache.clone(),
@@ -414,6 +2328,10 @@ impl Clone for SolanaRpcClient {
 
 #[async_trait]
 impl TokenDataProvider for SolanaRpcClient {
+    fn begin_analysis(&self) -> Self {
+        SolanaRpcClient::begin_analysis(self)
+    }
+
     async fn fetch_token_data(&self, mint: &Pubkey) -> Result<TokenData> {
         SolanaRpcClient::fetch_token_data(self, mint).await
     }
@@ -426,13 +2344,129 @@ impl TokenDataProvider for SolanaRpcClient {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use base64::Engine;
+    use serde_json::{json, Value};
+    use solana_program::program_pack::Pack;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    /// JSON for a legacy SPL mint account as returned by `getMultipleAccounts`
+    fn mint_account(supply: u64, mint_authority: Option<Pubkey>) -> Value {
+        let mint = Mint {
+            mint_authority: mint_authority.into(),
+            supply,
+            decimals: 6,
+            is_initialized: true,
+            freeze_authority: COption::None,
+        };
+        let mut data = vec![0u8; Mint::LEN];
+        mint.pack_into_slice(&mut data);
+
+        json!({
+            "lamports": 1_461_600,
+            "data": [base64::engine::general_purpose::STANDARD.encode(data), "base64"],
+            "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            "executable": false,
+            "rentEpoch": 0,
+            "space": Mint::LEN,
+        })
+    }
+
+    /// JSON for an SPL token account of `mint` held by `owner`
+    fn token_account(mint: Pubkey, owner: Pubkey) -> Value {
+        let account = TokenAccount {
+            mint,
+            owner,
+            amount: 1,
+            state: spl_token_2022::state::AccountState::Initialized,
+            ..TokenAccount::default()
+        };
+        let mut data = vec![0u8; TokenAccount::LEN];
+        account.pack_into_slice(&mut data);
+
+        json!({
+            "lamports": 2_039_280,
+            "data": [base64::engine::general_purpose::STANDARD.encode(data), "base64"],
+            "owner": "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA",
+            "executable": false,
+            "rentEpoch": 0,
+            "space": TokenAccount::LEN,
+        })
+    }
+
+    /// Local JSON-RPC endpoint answering every request with `accounts` at `slot`
+    async fn mock_endpoint(slot: u64, accounts: Value) -> String {
+        mock_endpoint_with(slot, move |_| accounts.clone()).await
+    }
+
+    /// Local JSON-RPC endpoint whose `value` is computed from each request
+    async fn mock_endpoint_with<F>(slot: u64, respond: F) -> String
+    where
+        F: Fn(&Value) -> Value + Send + Sync + 'static,
+    {
+        mock_rpc(move |request| json!({ "context": { "slot": slot }, "value": respond(request) }))
+            .await
+    }
+
+    /// Local JSON-RPC endpoint whose whole `result` is computed from each request
+    async fn mock_rpc<F>(respond: F) -> String
+    where
+        F: Fn(&Value) -> Value + Send + Sync + 'static,
+    {
+        let respond = Arc::new(respond);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let respond = Arc::clone(&respond);
+                tokio::spawn(async move {
+                    let mut request = Vec::new();
+                    let mut chunk = [0u8; 4096];
+                    let body_start = loop {
+                        match stream.read(&mut chunk).await {
+                            Ok(0) | Err(_) => return,
+                            Ok(n) => request.extend_from_slice(&chunk[..n]),
+                        }
+                        let Some(end) = request.windows(4).position(|w| w == b"\r\n\r\n") else {
+                            continue;
+                        };
+                        let headers = String::from_utf8_lossy(&request[..end]).to_lowercase();
+                        let length: usize = headers
+                            .lines()
+                            .find_map(|l| l.strip_prefix("content-length:"))
+                            .and_then(|v| v.trim().parse().ok())
+                            .unwrap_or(0);
+                        if request.len() >= end + 4 + length {
+                            break end + 4;
+                        }
+                    };
+
+                    let request: Value = serde_json::from_slice(&request[body_start..]).unwrap();
+                    let body = json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": respond(&request),
+                    })
+                    .to_string();
+                    let response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        body.len(),
+                        body
+                    );
+                    let _ = stream.write_all(response.as_bytes()).await;
+                });
+            }
+        });
+
+        url
+    }
 
     #[test]
     fn test_metadata_pda_derivation() {
         let mint = Pubkey::new_unique();
-        let metadata_seeds = &[b"metadata", mpl_token_metadata::ID.as_ref(), mint.as_ref()];
+        let metadata_seeds = &[b"metadata", METADATA_PROGRAM_ID.as_ref(), mint.as_ref()];
 
-        let (pda, _bump) = Pubkey::find_program_address(metadata_seeds, &mpl_token_metadata::ID);
+        let (pda, _bump) = Pubkey::find_program_address(metadata_seeds, &METADATA_PROGRAM_ID);
 
         // PDA should be valid and non-default
         assert_ne!(pda, Pubkey::default());
@@ -458,7 +2492,242 @@ mod tests {
 
         let cloned = client.clone();
 
-        // Both should share the same Arc<RpcClient>
-        assert!(Arc::ptr_eq(&client.client, &cloned.client));
+        // Both should share the same endpoint pool
+        assert!(Arc::ptr_eq(&client.pool, &cloned.pool));
+    }
+
+    #[tokio::test]
+    async fn test_empty_endpoint_list_is_rejected() {
+        assert!(SolanaRpcClient::with_endpoints(&[], RpcPolicy::default()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_expired_deadline_surfaces_as_timeout() {
+        let client = SolanaRpcClient::with_policy(
+            "http://127.0.0.1:9",
+            RpcPolicy {
+                deadline: Some(Duration::ZERO),
+                ..RpcPolicy::default()
+            },
+        )
+        .unwrap();
+
+        let analysis = client.begin_analysis();
+
+        let err = analysis
+            .fetch_token_data(&Pubkey::new_unique())
+            .await
+            .unwrap_err();
+        assert!(RiskError::is_timeout_err(&err));
+
+        // Wallet ages of the top holders are bound by the same deadline
+        let mut holders = vec![TokenHolder {
+            address: Pubkey::new_unique(),
+            amount: 1_000,
+            percentage: 100.0,
+            wallet_age_days: None,
+            wallet_age_complete: false,
+            funder: None,
+            label: None,
+        }];
+        let err = analysis.enrich_holder_ages(&mut holders).await.unwrap_err();
+        assert!(RiskError::is_timeout_err(&err));
+
+        // The deadline belonged to that analysis alone
+        assert!(matches!(client.remaining(), Ok(None)));
+    }
+
+    #[tokio::test]
+    async fn test_quorum_flags_disagreeing_endpoint() {
+        let authority = Pubkey::new_unique();
+        let honest = mock_endpoint(100, json!([mint_account(1_000, None), null])).await;
+        let replica = mock_endpoint(105, json!([mint_account(1_000, None), null])).await;
+        let lying = mock_endpoint(100, json!([mint_account(1_000, Some(authority)), null])).await;
+        let dead = "http://127.0.0.1:9".to_string();
+
+        // The key in the lying endpoint's URL stays out of the warnings
+        let client = SolanaRpcClient::with_endpoints(
+            &[
+                honest.clone(),
+                replica,
+                format!("{}/?api-key=secret", lying),
+                dead,
+            ],
+            RpcPolicy::default(),
+        )
+        .unwrap();
+        // A quorum can't be larger than the endpoint list
+        assert!(client.clone().with_quorum(5).is_err());
+        let client = client.with_quorum(4).unwrap();
+
+        let check = client.cross_check(&Pubkey::new_unique()).await.unwrap();
+
+        assert_eq!(check.requested, 4);
+        assert_eq!(check.responded, 3);
+        assert_eq!(check.min_context_slot, Some(100));
+        assert_eq!(check.unavailable.len(), 1);
+        assert_eq!(
+            check.disagreements,
+            vec![format!(
+                "mint authority: none at {} vs {} at {}",
+                honest, authority, lying
+            )]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_quorum_off_by_default() {
+        let client = SolanaRpcClient::new("http://127.0.0.1:9", Duration::from_secs(1)).unwrap();
+
+        assert!(client.cross_check(&Pubkey::new_unique()).await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_owners_resolved_in_batches() {
+        let mint = Pubkey::new_unique();
+        let owner = Pubkey::new_unique();
+        let requests = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let counter = Arc::clone(&requests);
+        let url = mock_endpoint_with(1, move |request| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            // The first account of each chunk is a token account, the rest don't exist
+            let keys = request["params"][0].as_array().unwrap().len();
+            let mut value = vec![Value::Null; keys];
+            value[0] = token_account(mint, owner);
+            Value::Array(value)
+        })
+        .await;
+
+        let client = SolanaRpcClient::new(&url, Duration::from_secs(5)).unwrap();
+        let token_accounts: Vec<Pubkey> = (0..150).map(|_| Pubkey::new_unique()).collect();
+
+        let owners = client
+            .get_token_account_owners(&token_accounts)
+            .await
+            .unwrap();
+
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+        assert_eq!(owners.len(), 150);
+        assert_eq!(owners[0], Some(owner));
+        assert_eq!(owners[1], None);
+        assert_eq!(owners[100], Some(owner));
+    }
+
+    #[tokio::test]
+    async fn test_deep_scan_aggregates_by_owner() {
+        let (whale, minnow, empty) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+
+        // `dataSlice` answer: owner followed by amount
+        let slice = |owner: Pubkey, amount: u64| {
+            let mut data = owner.to_bytes().to_vec();
+            data.extend_from_slice(&amount.to_le_bytes());
+            json!({
+                "pubkey": Pubkey::new_unique().to_string(),
+                "account": {
+                    "lamports": 2_039_280,
+                    "data": [base64::engine::general_purpose::STANDARD.encode(data), "base64"],
+                    "owner": LEGACY_TOKEN_PROGRAM_ID.to_string(),
+                    "executable": false,
+                    "rentEpoch": 0,
+                    "space": TOKEN_ACCOUNT_LEN,
+                },
+            })
+        };
+        let legacy_accounts = json!([
+            slice(whale, 600),
+            slice(minnow, 100),
+            slice(whale, 300),
+            slice(empty, 0),
+        ]);
+
+        let url = mock_endpoint_with(1, move |request| {
+            if request["params"][0] == LEGACY_TOKEN_PROGRAM_ID.to_string() {
+                legacy_accounts.clone()
+            } else {
+                json!([])
+            }
+        })
+        .await;
+
+        let client = SolanaRpcClient::new(&url, Duration::from_secs(5)).unwrap();
+        let (holders, counts, _) = client
+            .scan_holders(&Pubkey::new_unique(), 1_000)
+            .await
+            .unwrap();
+
+        assert!(counts.exact);
+        assert_eq!(counts.total, 3);
+        assert_eq!(counts.non_zero, 2);
+        assert_eq!(holders[0].address, whale);
+        assert_eq!(holders[0].amount, 900);
+        assert_eq!(holders[0].percentage, 90.0);
+        assert_eq!(holders[1].address, minnow);
+    }
+
+    #[tokio::test]
+    async fn test_signature_history_pages_backwards() {
+        let signatures: Vec<String> = (0..SIGNATURE_PAGE_LIMIT + 2)
+            .map(|_| Signature::new_unique().to_string())
+            .collect();
+        let requests = Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+        let history = signatures.clone();
+        let counter = Arc::clone(&requests);
+        let url = mock_rpc(move |request| {
+            counter.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            // Newest first; `before` continues after the given signature
+            let start = request["params"][1]["before"]
+                .as_str()
+                .map(|before| history.iter().position(|s| s == before).unwrap() + 1)
+                .unwrap_or(0);
+            let page: Vec<Value> = history[start..]
+                .iter()
+                .take(SIGNATURE_PAGE_LIMIT)
+                .enumerate()
+                .map(|(i, signature)| {
+                    json!({
+                        "signature": signature,
+                        "slot": 10_000 - start - i,
+                        "err": null,
+                        "memo": null,
+                        "blockTime": 1_700_000_000 - (start + i) as i64,
+                    })
+                })
+                .collect();
+            Value::Array(page)
+        })
+        .await;
+
+        let client = SolanaRpcClient::new(&url, Duration::from_secs(5)).unwrap();
+        let history = client
+            .signature_history(&Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        assert_eq!(history.signatures.len(), signatures.len());
+        assert_eq!(
+            &history.signatures.last().unwrap().signature,
+            signatures.last().unwrap()
+        );
+        assert!(history.complete);
+        assert_eq!(requests.load(std::sync::atomic::Ordering::SeqCst), 2);
+
+        // One page of budget stops short of the first transaction
+        let client = client.with_history_budget(1, None);
+        let history = client
+            .signature_history(&Pubkey::new_unique())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            history.signatures.last().unwrap().signature,
+            signatures[SIGNATURE_PAGE_LIMIT - 1]
+        );
+        assert!(!history.complete);
     }
 }